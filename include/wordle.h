@@ -0,0 +1,52 @@
+/* The C interface of the wordle crate, built with the `ffi` feature. See src/ffi.rs. */
+
+#ifndef WORDLE_H
+#define WORDLE_H
+
+#include <stddef.h>
+#include <stdint.h>
+#include <sys/types.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Feedback for one letter. */
+#define WORDLE_WRONG 0
+#define WORDLE_MISPLACED 1
+#define WORDLE_CORRECT 2
+
+typedef struct Wordle Wordle;
+typedef struct WordleHistory WordleHistory;
+
+/* Builds an engine over the embedded dictionary, or returns NULL if the library was
+ * built without one. */
+Wordle *wordle_new(void);
+/* Builds an engine over a dictionary file, or returns NULL if it cannot be loaded. */
+Wordle *wordle_from_dictionary(const char *path);
+void wordle_free(Wordle *wordle);
+
+/* Writes one WORDLE_* value per letter of the guess to mask and returns the number of
+ * letters, or -1 if the words are not as long as the engine's words or mask is too
+ * short. */
+int wordle_compute_mask(const Wordle *wordle, const char *answer, const char *guess, uint8_t *mask, size_t mask_len);
+
+WordleHistory *wordle_history_new(void);
+/* Adds a guess and its feedback to a history of a game played by wordle. Returns 0, or
+ * -1 if the guess is not as long as the engine's words or the mask is invalid. */
+int wordle_history_push(const Wordle *wordle, WordleHistory *history, const char *guess, const uint8_t *mask, size_t mask_len);
+void wordle_history_free(WordleHistory *history);
+
+/* These write text to out like snprintf: they return its full length, or -1 on a NULL
+ * argument, and write as much as fits followed by a nul. */
+
+/* The possible answers consistent with the history, separated by spaces. */
+ssize_t wordle_candidates(const Wordle *wordle, const WordleHistory *history, char *out, size_t out_len);
+/* The best next guess. */
+ssize_t wordle_hint(const Wordle *wordle, const WordleHistory *history, char *out, size_t out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
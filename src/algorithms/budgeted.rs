@@ -0,0 +1,37 @@
+use std::time::{Duration, Instant};
+
+use crate::{Guesser, Guess, Profile};
+
+/// Wraps a guesser and gives it a time budget for each turn.
+///
+/// Every guess is asked for with [`Guesser::guess_by`], so a guesser that can stop early,
+/// such as [`super::Entropy`] or [`super::Deep`], returns the best guess it found within
+/// the budget. Guessers that cannot stop early take as long as they take.
+pub struct Budgeted<G> {
+	inner: G,
+	budget: Duration,
+}
+
+impl<G: Guesser> Budgeted<G> {
+	pub fn new(inner: G, budget: Duration) -> Self {
+		Self { inner, budget }
+	}
+}
+
+impl<G: Guesser> Guesser for Budgeted<G> {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		self.inner.guess_by(history, Instant::now() + self.budget)
+	}
+
+	fn guess_by(&mut self, history: &[Guess], deadline: Instant) -> String {
+		self.inner.guess_by(history, deadline.min(Instant::now() + self.budget))
+	}
+
+	fn reset(&mut self) {
+		self.inner.reset();
+	}
+
+	fn profile(&self) -> Profile {
+		self.inner.profile()
+	}
+}
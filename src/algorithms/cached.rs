@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::{Guesser, Guess, Profile};
+
+/// The first moves of a guesser, remembered across games. Share one between the
+/// guessers of a benchmark through [`Cached`].
+pub struct MoveCache {
+	turns: usize,
+	/// Guesses keyed by the history they were made after, in compact form.
+	moves: Mutex<HashMap<String, String>>,
+}
+
+impl MoveCache {
+	/// Remembers the guesses of the first `turns` turns: 1 caches only the opener, 2
+	/// also the second guess for each feedback the opener can get, and so on.
+	pub fn new(turns: usize) -> Arc<Self> {
+		Arc::new(Self { turns, moves: Mutex::new(HashMap::new()) })
+	}
+
+	/// The number of moves remembered so far.
+	pub fn len(&self) -> usize {
+		self.moves.lock().expect("cache lock is not poisoned").len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+/// Wraps a guesser and reuses its early guesses from a [`MoveCache`] instead of
+/// computing them again in every game.
+///
+/// Only use this with guessers whose guess depends on the history alone, such as
+/// [`super::Entropy`]; the inner guesser is not asked at all on cached turns.
+pub struct Cached<G> {
+	inner: G,
+	cache: Arc<MoveCache>,
+}
+
+impl<G: Guesser> Cached<G> {
+	pub fn new(inner: G, cache: &Arc<MoveCache>) -> Self {
+		Self { inner, cache: Arc::clone(cache) }
+	}
+}
+
+impl<G: Guesser> Guesser for Cached<G> {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		if history.len() >= self.cache.turns {
+			return self.inner.guess(history);
+		}
+		let key = key(history);
+		if let Some(guess) = self.cache.moves.lock().expect("cache lock is not poisoned").get(&key) {
+			return guess.clone();
+		}
+		// computed without holding the lock, so other threads are not held up meanwhile
+		let guess = self.inner.guess(history);
+		self.cache.moves.lock().expect("cache lock is not poisoned").insert(key, guess.clone());
+		guess
+	}
+
+	/// Uses a cached guess if there is one, and otherwise asks the inner guesser without
+	/// caching its guess, which may have been cut short.
+	fn guess_by(&mut self, history: &[Guess], deadline: Instant) -> String {
+		if history.len() < self.cache.turns {
+			if let Some(guess) = self.cache.moves.lock().expect("cache lock is not poisoned").get(&key(history)) {
+				return guess.clone();
+			}
+		}
+		self.inner.guess_by(history, deadline)
+	}
+
+	fn reset(&mut self) {
+		self.inner.reset();
+	}
+
+	fn profile(&self) -> Profile {
+		self.inner.profile()
+	}
+}
+
+/// The key of the guess made after `history`.
+fn key(history: &[Guess]) -> String {
+	history.iter().map(|guess| guess.to_string()).collect::<Vec<_>>().join(" ")
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use crate::{Guesser, Guess, PackedCorrectness, Profile, Wordle};
+
+use super::Entropy;
+
+/// How many first guesses, ranked by single-step entropy, are searched by default.
+const DEFAULT_BUDGET: usize = 10;
+/// Roughly how many bits of information a good guess gains on a small candidate set,
+/// used to estimate the guesses still needed after the two searched ones.
+const BITS_PER_GUESS: f64 = 4.0;
+
+/// Picks the guess that minimizes the expected number of guesses left, looking two
+/// guesses ahead.
+///
+/// The `budget` guesses with the most single-step information (see [`Entropy`]) are
+/// the first guesses considered, together with the remaining answers when there are no
+/// more of them than the budget. For each feedback a first guess can get, the best
+/// second guess is searched among the same guesses and the answers left in that case.
+/// What remains after two guesses is estimated from the number of answers left.
+pub struct Deep {
+	wordle: Wordle,
+	entropy: Entropy,
+	budget: usize,
+	/// Time spent in the search, on top of what `entropy` counts.
+	searching: Duration,
+}
+
+impl Deep {
+	pub fn new(wordle: &Wordle) -> Self {
+		Self {
+			wordle: wordle.clone(),
+			entropy: Entropy::new(wordle),
+			budget: DEFAULT_BUDGET,
+			searching: Duration::ZERO,
+		}
+	}
+
+	/// Sets how many first guesses are searched. Larger budgets play better but take
+	/// quadratically longer.
+	pub fn with_budget(mut self, budget: usize) -> Self {
+		self.budget = budget.max(1);
+		self
+	}
+
+	/// The expected number of guesses to finish when guessing `guess` with `candidates`
+	/// left, searching `depth` guesses before estimating.
+	fn cost(&self, guess: &str, candidates: &[&'static str], options: &[String], depth: usize) -> f64 {
+		let correct = PackedCorrectness::correct(guess.chars().count());
+		let mut buckets: HashMap<PackedCorrectness, Vec<&'static str>> = HashMap::new();
+		for &candidate in candidates {
+			buckets.entry(PackedCorrectness::compute(candidate, guess, self.wordle.duplicate_rule)).or_default().push(candidate);
+		}
+		let rest: f64 = buckets.iter().map(|(&pattern, bucket)| {
+			let p = bucket.len() as f64 / candidates.len() as f64;
+			p * match bucket.len() {
+				_ if pattern == correct => 0.0,
+				1 => 1.0,
+				n if depth <= 1 => 1.0 + (n as f64).log2() / BITS_PER_GUESS,
+				_ => options.iter().map(String::as_str).chain(bucket.iter().copied())
+					.map(|next| self.cost(next, bucket, options, depth - 1))
+					.fold(f64::INFINITY, f64::min),
+			}
+		}).sum();
+		1.0 + rest
+	}
+
+	/// The best guess, searching until `deadline` if there is one. The first guess
+	/// searched is the one with the most information, so it is the one guessed if time
+	/// runs out before any other.
+	fn choose(&mut self, history: &[Guess], deadline: Option<Instant>) -> String {
+		let options: Vec<String> = self.entropy.top(history, self.budget, deadline)
+			.into_iter()
+			.map(|(word, _)| word)
+			.collect();
+		let remaining = self.entropy.remaining().to_vec();
+		if remaining.len() <= 2 {
+			if let Some(first) = remaining.first() {
+				return first.to_string();
+			}
+		}
+		let mut firsts = options.clone();
+		if remaining.len() <= self.budget {
+			firsts.extend(remaining.iter().map(|word| word.to_string()));
+		}
+		let start = Instant::now();
+		let mut best: Option<(f64, String)> = None;
+		for guess in firsts {
+			if best.is_some() && deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+				break;
+			}
+			let cost = self.cost(&guess, &remaining, &options, 2);
+			if best.as_ref().is_none_or(|(best_cost, _)| cost < *best_cost) {
+				best = Some((cost, guess));
+			}
+		}
+		self.searching += start.elapsed();
+		best.expect("dictionary is not empty").1
+	}
+}
+
+impl Guesser for Deep {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		self.choose(history, None)
+	}
+
+	fn guess_by(&mut self, history: &[Guess], deadline: Instant) -> String {
+		self.choose(history, Some(deadline))
+	}
+
+	fn reset(&mut self) {
+		self.entropy.reset();
+	}
+
+	fn profile(&self) -> Profile {
+		let mut profile = self.entropy.profile();
+		profile.scoring += self.searching;
+		profile
+	}
+}
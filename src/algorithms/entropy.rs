@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Instant;
+
+use super::candidates_first;
+use crate::{CandidateSet, Guesser, Guess, PackedCorrectness, PatternMatrix, Profile, Wordle};
+
+/// Frequency rank at which the prior considers a word as likely an answer as not.
+const PRIOR_CUTOFF: f64 = 3000.0;
+/// How many ranks it takes the prior to move noticeably away from even odds.
+const PRIOR_WIDTH: f64 = 300.0;
+/// The prior weight of an answer that is not in the dictionary.
+const PRIOR_UNKNOWN: f64 = 1e-9;
+
+/// Picks the guess with the highest expected information gain.
+///
+/// Every dictionary word is considered as a guess. It is scored by the entropy of the
+/// distribution of correctness patterns it would produce over the possible answers still
+/// consistent with the history. Among equally good guesses, one that could itself be the
+/// answer is preferred. In hard mode only guesses that respect the revealed hints are
+/// considered.
+///
+/// By default every remaining answer is considered equally likely. With
+/// [`Entropy::with_frequency_prior`] answers are instead weighted by how common they are.
+/// [`Entropy::with_scorer`] replaces the objective altogether.
+pub struct Entropy {
+	wordle: Wordle,
+	candidates: CandidateSet,
+	/// How likely each word is to be the answer, relative to the others.
+	prior: Option<HashMap<&'static str, f64>>,
+	/// The prior weight of each remaining candidate.
+	remaining_weights: Vec<f64>,
+	matrix: Option<Arc<PatternMatrix>>,
+	/// Answer indices of the remaining candidates in `matrix`, if every one has one.
+	remaining_answers: Option<Vec<usize>>,
+	scorer: Option<Arc<Scorer>>,
+	profile: Profile,
+}
+
+/// Scores a guess from its expected information in bits, its probability of being the
+/// answer and the number of remaining candidates. Higher is better.
+type Scorer = dyn Fn(f64, f64, usize) -> f64 + Send + Sync;
+
+impl Entropy {
+	pub fn new(wordle: &Wordle) -> Self {
+		Self {
+			wordle: wordle.clone(),
+			remaining_weights: vec![1.0; wordle.answers().len()],
+			candidates: CandidateSet::new(wordle),
+			prior: None,
+			matrix: None,
+			remaining_answers: None,
+			scorer: None,
+			profile: Profile::default(),
+		}
+	}
+
+	/// Weights each answer by a sigmoid of its frequency rank in the dictionary, so that
+	/// the few thousand most common words are considered likely answers and obscure
+	/// words are not.
+	pub fn with_frequency_prior(mut self) -> Self {
+		let mut ranked: Vec<_> = self.wordle.words_with_frequency().collect();
+		ranked.sort_unstable_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+		self.prior = Some(ranked.into_iter().enumerate().map(|(rank, (word, _))| {
+			let x = (PRIOR_CUTOFF - rank as f64) / PRIOR_WIDTH;
+			(word, 1.0 / (1.0 + (-x).exp()))
+		}).collect());
+		self
+	}
+
+	/// Looks patterns up in a precomputed matrix instead of computing them. Words missing
+	/// from the matrix fall back to computing.
+	pub fn with_matrix(mut self, matrix: Arc<PatternMatrix>) -> Self {
+		assert_eq!(matrix.rule(), self.wordle.duplicate_rule, "matrix was computed with a different duplicate rule");
+		assert_eq!(matrix.word_len(), self.wordle.config().word_len, "matrix was computed for a different word length");
+		self.matrix = Some(matrix);
+		self
+	}
+
+	/// Scores guesses with `scorer` instead of by their information alone, for trying
+	/// other objectives. It is given the expected information in bits, the probability
+	/// that the guess is the answer (zero if it cannot be) and the number of remaining
+	/// candidates. Ties still go to the likelier answer.
+	///
+	/// For example, `|bits, p, _| p + (1.0 - p) * bits` also rewards a chance to win now.
+	pub fn with_scorer(mut self, scorer: impl Fn(f64, f64, usize) -> f64 + Send + Sync + 'static) -> Self {
+		self.scorer = Some(Arc::new(scorer));
+		self
+	}
+
+	/// Returns the `n` best next guesses with their scores, best first. The score is the
+	/// expected information in bits unless a scorer was set.
+	pub fn suggest_top(&mut self, history: &[Guess], n: usize) -> Vec<(String, f64)> {
+		self.top(history, n, None)
+	}
+
+	/// Like [`Entropy::suggest_top`], but stops scoring at `deadline`.
+	pub(super) fn top(&mut self, history: &[Guess], n: usize, deadline: Option<Instant>) -> Vec<(String, f64)> {
+		self.update(history);
+		let mut scores = self.timed_scores(history, deadline);
+		scores.sort_by(|a, b| {
+			b.1.total_cmp(&a.1).then(b.2.total_cmp(&a.2)).then(a.0.cmp(b.0))
+		});
+		scores.into_iter().take(n).map(|(word, score, _)| (word.to_string(), score)).collect()
+	}
+
+	/// The answers still consistent with the history seen by the last call.
+	pub fn remaining(&self) -> &[&'static str] {
+		self.candidates.remaining()
+	}
+
+	fn update(&mut self, history: &[Guess]) {
+		let start = Instant::now();
+		let remaining = self.candidates.update(history);
+		self.remaining_weights = match &self.prior {
+			Some(prior) => remaining.iter().map(|word| prior.get(word).copied().unwrap_or(PRIOR_UNKNOWN)).collect(),
+			None => vec![1.0; remaining.len()],
+		};
+		self.remaining_answers = self.matrix.as_ref().and_then(|matrix| {
+			remaining.iter().map(|answer| matrix.answer_index(answer)).collect()
+		});
+		self.profile.filtering += start.elapsed();
+	}
+
+	fn timed_scores(&mut self, history: &[Guess], deadline: Option<Instant>) -> Vec<(&'static str, f64, f64)> {
+		let start = Instant::now();
+		let scores = self.scores(history, deadline);
+		self.profile.scoring += start.elapsed();
+		scores
+	}
+
+	/// Scores every guess, also noting its prior weight as an answer (zero if it cannot
+	/// be the answer). The candidates are scored first, and once `deadline` passes no more
+	/// guesses are, though at least one always is.
+	fn scores(&self, history: &[Guess], deadline: Option<Instant>) -> Vec<(&'static str, f64, f64)> {
+		let candidates: HashMap<&str, f64> = self.remaining().iter().copied().zip(self.remaining_weights.iter().copied()).collect();
+		let total: f64 = self.remaining_weights.iter().sum();
+		let mut scores = Vec::new();
+		for guess in candidates_first(self.wordle.words(), self.remaining()).filter(|guess| self.wordle.is_allowed(guess, history)) {
+			if !scores.is_empty() && deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+				break;
+			}
+			let bits = self.entropy(guess);
+			let weight = candidates.get(guess).copied().unwrap_or(0.0);
+			let score = match &self.scorer {
+				Some(scorer) => scorer(bits, weight / total, self.remaining().len()),
+				None => bits,
+			};
+			scores.push((guess, score, weight));
+		}
+		scores
+	}
+
+	/// The best guess, scoring guesses until `deadline` if there is one.
+	fn choose(&mut self, history: &[Guess], deadline: Option<Instant>) -> String {
+		self.update(history);
+		if let [only] = self.remaining()[..] {
+			return only.to_string();
+		}
+		self.timed_scores(history, deadline).into_iter()
+			.reduce(|best, next| if better(next, best) { next } else { best })
+			.expect("dictionary is not empty")
+			.0
+			.to_string()
+	}
+
+	fn entropy(&self, guess: &str) -> f64 {
+		let mut buckets = vec![0.0; PackedCorrectness::count(self.wordle.config().word_len)];
+		let row = self.matrix.as_ref().and_then(|matrix| Some((matrix, matrix.guess_index(guess)?)));
+		match (row, &self.remaining_answers) {
+			(Some((matrix, row)), Some(answers)) => {
+				for (&answer, &weight) in answers.iter().zip(&self.remaining_weights) {
+					buckets[matrix.get(row, answer).index()] += weight;
+				}
+			}
+			_ => {
+				for (&candidate, &weight) in self.remaining().iter().zip(&self.remaining_weights) {
+					buckets[PackedCorrectness::compute(candidate, guess, self.wordle.duplicate_rule).index()] += weight;
+				}
+			}
+		}
+		let total: f64 = self.remaining_weights.iter().sum();
+		buckets.iter().filter(|&&weight| weight > 0.0).map(|&weight| {
+			let p = weight / total;
+			-p * p.log2()
+		}).sum()
+	}
+}
+
+impl Guesser for Entropy {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		self.choose(history, None)
+	}
+
+	fn guess_by(&mut self, history: &[Guess], deadline: Instant) -> String {
+		self.choose(history, Some(deadline))
+	}
+
+	fn reset(&mut self) {
+		self.candidates.reset();
+	}
+
+	fn profile(&self) -> Profile {
+		self.profile
+	}
+}
+
+/// Whether `next` beats `best`: it has more information or, on a tie, is a likelier answer.
+fn better((_, score, weight): (&str, f64, f64), (_, best_score, best_weight): (&str, f64, f64)) -> bool {
+	score > best_score || (score == best_score && weight > best_weight)
+}
@@ -0,0 +1,91 @@
+use std::time::Instant;
+
+use super::candidates_first;
+use crate::{CandidateSet, Guesser, Guess, PackedCorrectness, Profile, Wordle};
+
+/// Picks the guess whose largest bucket of remaining answers is smallest, following
+/// Knuth's Mastermind strategy.
+///
+/// This optimizes the worst case rather than the average: the guesses minimize how many
+/// answers can possibly remain, whatever the feedback. Among equally good guesses, one
+/// that could itself be the answer is preferred, and then the alphabetically first. In
+/// hard mode only guesses that respect the revealed hints are considered.
+pub struct Minimax {
+	wordle: Wordle,
+	candidates: CandidateSet,
+	profile: Profile,
+}
+
+impl Minimax {
+	pub fn new(wordle: &Wordle) -> Self {
+		Self { wordle: wordle.clone(), candidates: CandidateSet::new(wordle), profile: Profile::default() }
+	}
+
+	/// The size of the largest bucket `guess` splits `remaining` into, or `None` as soon as
+	/// it is found to exceed `limit`.
+	fn worst(&self, guess: &str, remaining: &[&str], limit: usize) -> Option<usize> {
+		let mut buckets = vec![0; PackedCorrectness::count(self.wordle.config().word_len)];
+		let mut worst = 0;
+		for answer in remaining {
+			let bucket = &mut buckets[PackedCorrectness::compute(answer, guess, self.wordle.duplicate_rule).index()];
+			*bucket += 1;
+			worst = worst.max(*bucket);
+			if worst > limit {
+				return None;
+			}
+		}
+		Some(worst)
+	}
+
+	/// The best guess, searching until `deadline` if there is one. The candidates are
+	/// searched first so that a search cut short has found one; otherwise the order
+	/// makes no difference, since candidates win ties.
+	fn choose(&mut self, history: &[Guess], deadline: Option<Instant>) -> String {
+		let start = Instant::now();
+		self.candidates.update(history);
+		self.profile.filtering += start.elapsed();
+		let remaining = self.candidates.remaining();
+		if let [only] = remaining[..] {
+			return only.to_string();
+		}
+		let start = Instant::now();
+		let mut best: Option<(&str, usize, bool)> = None;
+		for guess in candidates_first(self.wordle.words(), remaining) {
+			if best.is_some() && deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+				break;
+			}
+			if !self.wordle.is_allowed(guess, history) {
+				continue;
+			}
+			let limit = best.map_or(usize::MAX, |(_, worst, _)| worst);
+			let Some(worst) = self.worst(guess, remaining, limit) else {
+				continue;
+			};
+			let candidate = remaining.binary_search(&guess).is_ok();
+			if best.is_none_or(|(_, best_worst, best_candidate)| worst < best_worst || (worst == best_worst && candidate && !best_candidate)) {
+				best = Some((guess, worst, candidate));
+			}
+		}
+		let guess = best.expect("dictionary is not empty").0.to_string();
+		self.profile.scoring += start.elapsed();
+		guess
+	}
+}
+
+impl Guesser for Minimax {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		self.choose(history, None)
+	}
+
+	fn guess_by(&mut self, history: &[Guess], deadline: Instant) -> String {
+		self.choose(history, Some(deadline))
+	}
+
+	fn reset(&mut self) {
+		self.candidates.reset();
+	}
+
+	fn profile(&self) -> Profile {
+		self.profile
+	}
+}
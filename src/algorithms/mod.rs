@@ -1,2 +1,31 @@
+mod budgeted;
+mod cached;
+mod deep;
+mod entropy;
+mod minimax;
 mod naive;
-pub use naive::Naive;
\ No newline at end of file
+mod opening_book;
+mod openers;
+mod positional;
+mod random;
+mod registry;
+mod sanitized;
+pub use budgeted::Budgeted;
+pub use cached::{Cached, MoveCache};
+pub use deep::Deep;
+pub use entropy::Entropy;
+pub use minimax::Minimax;
+pub use naive::Naive;
+pub use opening_book::OpeningBook;
+pub use openers::optimal_openers;
+pub use positional::PositionalFrequency;
+pub use random::Random;
+pub use registry::{algorithm, Algorithm, ALGORITHMS};
+pub use sanitized::Sanitized;
+
+/// The dictionary words with the `remaining` candidates first, both in alphabetical
+/// order, so that a search cut short has looked at the guesses that could win.
+/// `remaining` must be sorted, as [`crate::CandidateSet`] keeps it.
+fn candidates_first<'a>(words: &'a [&'static str], remaining: &'a [&'static str]) -> impl Iterator<Item = &'static str> + 'a {
+	remaining.iter().copied().chain(words.iter().copied().filter(|word| remaining.binary_search(word).is_err()))
+}
@@ -1,4 +1,4 @@
-use crate::{Guesser, Guess, Correctness};
+use crate::{Guesser, Guess};
 
 pub struct Naive;
 
@@ -8,6 +8,12 @@ impl Naive {
 	}
 }
 
+impl Default for Naive {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
 impl Guesser for Naive {
 	fn guess(&mut self, _history: &[Guess]) -> String {
 		"huhuh".into()
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use crate::{DuplicateRule, PackedCorrectness, Wordle};
+
+/// Returns every opening guess that solves all `candidates` in the fewest guaranteed
+/// guesses, provided that number is at most `turns`.
+///
+/// Openers are drawn from the whole dictionary. After the opener, follow-up guesses are
+/// drawn from the remaining candidates only, which keeps the exhaustive minimax search
+/// tractable; it is still exponential, so only use this on small candidate sets. The
+/// result is sorted and empty if no opener guarantees a solve within `turns`, or if there
+/// are no candidates to solve.
+pub fn optimal_openers(wordle: &Wordle, candidates: &[&str], turns: usize) -> Vec<String> {
+	if candidates.is_empty() {
+		return Vec::new();
+	}
+	let mut best = None;
+	let mut openers = Vec::new();
+	for &guess in wordle.words() {
+		let Some(depth) = worst_case(guess, candidates, turns, wordle.duplicate_rule) else {
+			continue;
+		};
+		match best {
+			Some(b) if depth > b => {}
+			Some(b) if depth == b => openers.push(guess.to_string()),
+			_ => {
+				best = Some(depth);
+				openers = vec![guess.to_string()];
+			}
+		}
+	}
+	openers.sort();
+	openers
+}
+
+/// The number of guesses needed in the worst case to solve `candidates` when starting
+/// with `guess`, or `None` if that takes more than `turns` guesses.
+fn worst_case(guess: &str, candidates: &[&str], turns: usize, rule: DuplicateRule) -> Option<usize> {
+	if turns == 0 {
+		return None;
+	}
+	let mut buckets: HashMap<PackedCorrectness, Vec<&str>> = HashMap::new();
+	for &candidate in candidates {
+		buckets.entry(PackedCorrectness::compute(candidate, guess, rule)).or_default().push(candidate);
+	}
+	let mut worst = 0;
+	for (pattern, bucket) in buckets {
+		let depth = if pattern == PackedCorrectness::correct(guess.chars().count()) {
+			1
+		}
+		else {
+			1 + solve(&bucket, turns - 1, rule)?
+		};
+		worst = worst.max(depth);
+	}
+	Some(worst)
+}
+
+fn solve(candidates: &[&str], turns: usize, rule: DuplicateRule) -> Option<usize> {
+	candidates.iter()
+		.filter_map(|&guess| worst_case(guess, candidates, turns, rule))
+		.min()
+}
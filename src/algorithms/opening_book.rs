@@ -0,0 +1,57 @@
+use std::time::Instant;
+
+use crate::{Guesser, Guess, Profile};
+
+/// Wraps a guesser and opens every game with a fixed sequence of probes, leaving the
+/// rest of the game to the inner guesser. Useful for judging an opening with a given
+/// follow-up strategy.
+///
+/// The probes are played whatever feedback they get, as a player with a set opening
+/// would; the book only ends early if a probe turns out to be the answer.
+pub struct OpeningBook<G> {
+	probes: Vec<String>,
+	inner: G,
+}
+
+impl<G: Guesser> OpeningBook<G> {
+	pub fn new<I, S>(probes: I, inner: G) -> Self
+	where
+		I: IntoIterator<Item = S>,
+		S: Into<String>,
+	{
+		Self { probes: probes.into_iter().map(Into::into).collect(), inner }
+	}
+
+	/// A book of a single opening guess.
+	pub fn opener(opener: &str, inner: G) -> Self {
+		Self::new([opener], inner)
+	}
+
+	pub fn probes(&self) -> &[String] {
+		&self.probes
+	}
+}
+
+impl<G: Guesser> Guesser for OpeningBook<G> {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		match self.probes.get(history.len()) {
+			Some(probe) => probe.clone(),
+			None => self.inner.guess(history),
+		}
+	}
+
+	fn guess_by(&mut self, history: &[Guess], deadline: Instant) -> String {
+		match self.probes.get(history.len()) {
+			Some(probe) => probe.clone(),
+			None => self.inner.guess_by(history, deadline),
+		}
+	}
+
+	fn reset(&mut self) {
+		self.inner.reset();
+	}
+
+	fn profile(&self) -> Profile {
+		self.inner.profile()
+	}
+}
@@ -0,0 +1,48 @@
+use crate::{CandidateSet, Guesser, Guess, Wordle};
+
+/// Guesses a uniformly random answer that is consistent with the history, as a baseline
+/// that any real strategy should beat.
+///
+/// The choices are determined by the seed: two guessers with the same seed make the same
+/// guesses given the same feedback. When no word at all fits the history, the guess is
+/// any dictionary word.
+pub struct Random {
+	wordle: Wordle,
+	candidates: CandidateSet,
+	seed: u64,
+	state: u64,
+}
+
+impl Random {
+	pub fn new(wordle: &Wordle, seed: u64) -> Self {
+		Self { wordle: wordle.clone(), candidates: CandidateSet::new(wordle), seed, state: seed }
+	}
+
+	/// The next number of the splitmix64 sequence.
+	fn next(&mut self) -> u64 {
+		self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+		let mut z = self.state;
+		z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+		z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+		z ^ (z >> 31)
+	}
+}
+
+impl Guesser for Random {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		let random = self.next();
+		let pool = match self.candidates.update(history) {
+			[] => self.wordle.words(),
+			remaining => remaining,
+		};
+		// scale to the range instead of taking a remainder, which would favor low indices
+		let index = ((random as u128 * pool.len() as u128) >> 64) as usize;
+		pool[index].to_string()
+	}
+
+	/// Starts the sequence over, so every game is played as by a new guesser.
+	fn reset(&mut self) {
+		self.state = self.seed;
+		self.candidates.reset();
+	}
+}
@@ -0,0 +1,60 @@
+use crate::{Guesser, Wordle};
+
+use super::{Deep, Entropy, Minimax, PositionalFrequency, Random};
+
+/// A guessing algorithm that can be picked by name at runtime, e.g. from a command-line
+/// flag.
+pub struct Algorithm {
+	pub name: &'static str,
+	pub description: &'static str,
+	/// Whether its guesses depend on the history alone, so that it can be
+	/// [`super::Cached`].
+	pub deterministic: bool,
+	/// Builds a fresh guesser for one game.
+	pub build: fn(&Wordle) -> Box<dyn Guesser>,
+}
+
+/// Every algorithm that can be selected by name.
+pub const ALGORITHMS: &[Algorithm] = &[
+	Algorithm {
+		name: "entropy",
+		description: "maximize expected information, all answers equally likely",
+		deterministic: true,
+		build: |wordle| Box::new(Entropy::new(wordle)),
+	},
+	Algorithm {
+		name: "prior",
+		description: "maximize expected information, common words more likely",
+		deterministic: true,
+		build: |wordle| Box::new(Entropy::new(wordle).with_frequency_prior()),
+	},
+	Algorithm {
+		name: "deep",
+		description: "minimize expected guesses, searching two guesses ahead",
+		deterministic: true,
+		build: |wordle| Box::new(Deep::new(wordle)),
+	},
+	Algorithm {
+		name: "minimax",
+		description: "minimize the number of answers left in the worst case",
+		deterministic: true,
+		build: |wordle| Box::new(Minimax::new(wordle)),
+	},
+	Algorithm {
+		name: "frequency",
+		description: "guess the candidate with the most common letters at each position",
+		deterministic: true,
+		build: |wordle| Box::new(PositionalFrequency::new(wordle)),
+	},
+	Algorithm {
+		name: "random",
+		description: "guess a random candidate, as a baseline",
+		deterministic: false,
+		build: |wordle| Box::new(Random::new(wordle, 0)),
+	},
+];
+
+/// The algorithm called `name`, if there is one.
+pub fn algorithm(name: &str) -> Option<&'static Algorithm> {
+	ALGORITHMS.iter().find(|algorithm| algorithm.name == name)
+}
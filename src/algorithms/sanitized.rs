@@ -0,0 +1,67 @@
+use std::time::Instant;
+
+use crate::{Guesser, Guess, Profile, Wordle};
+
+/// Wraps a guesser and replaces any guess that is not in the dictionary, or that ignores
+/// the revealed hints in hard mode.
+///
+/// An invalid guess is replaced by the alphabetically first allowed answer that is
+/// consistent with the history so far. If there is none, it is the first such
+/// dictionary word, or else the first allowed dictionary word. This keeps a buggy
+/// guesser from failing `Wordle::play`.
+pub struct Sanitized<G> {
+	inner: G,
+	wordle: Wordle,
+}
+
+impl<G: Guesser> Sanitized<G> {
+	pub fn new(wordle: &Wordle, inner: G) -> Self {
+		Self {
+			inner,
+			wordle: wordle.clone(),
+		}
+	}
+
+	/// `guess` if it may be played, or else a substitute.
+	fn sanitize(&self, guess: String, history: &[Guess]) -> String {
+		if self.wordle.is_allowed(&guess, history) {
+			guess
+		}
+		else {
+			self.substitute(history)
+		}
+	}
+
+	fn substitute(&self, history: &[Guess]) -> String {
+		let allowed = |word: &&str| self.wordle.is_allowed(word, history);
+		let words = || self.wordle.words().iter().copied();
+		self.wordle.candidates(history)
+			.filter(allowed)
+			.min()
+			.or_else(|| words().filter(|word| self.wordle.is_consistent(word, history)).find(allowed))
+			.or_else(|| words().find(allowed))
+			.or_else(|| words().next())
+			.expect("dictionary is not empty")
+			.to_string()
+	}
+}
+
+impl<G: Guesser> Guesser for Sanitized<G> {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		let guess = self.inner.guess(history);
+		self.sanitize(guess, history)
+	}
+
+	fn guess_by(&mut self, history: &[Guess], deadline: Instant) -> String {
+		let guess = self.inner.guess_by(history, deadline);
+		self.sanitize(guess, history)
+	}
+
+	fn reset(&mut self) {
+		self.inner.reset();
+	}
+
+	fn profile(&self) -> Profile {
+		self.inner.profile()
+	}
+}
@@ -0,0 +1,313 @@
+use std::fmt;
+use std::io::Write;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::json::Value;
+use crate::replay::Recorder;
+use crate::algorithms::OpeningBook;
+use crate::{GameResult, Guess, Guesser, Profile, ScoredGuess, WordGame, Wordle, WordleError};
+
+/// Summary of many games played by one algorithm.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Stats {
+	pub games: usize,
+	/// `wins[i]` is the number of games won in `i + 1` guesses.
+	pub wins: Vec<usize>,
+	pub failures: usize,
+	pub elapsed: Duration,
+}
+
+impl Stats {
+	/// Empty stats with a histogram row for each of `max_guesses` turns.
+	pub fn new(max_guesses: usize) -> Self {
+		Self { wins: vec![0; max_guesses], ..Self::default() }
+	}
+
+	pub fn record(&mut self, result: &GameResult) {
+		self.games += 1;
+		match result.solved_in() {
+			Some(n) => {
+				if self.wins.len() < n {
+					self.wins.resize(n, 0);
+				}
+				self.wins[n - 1] += 1;
+			}
+			None => self.failures += 1,
+		}
+	}
+
+	/// Adds the games counted in `other` to these stats. Elapsed times are not added up,
+	/// since merged runs usually overlap in time.
+	pub fn merge(&mut self, other: &Stats) {
+		self.games += other.games;
+		if self.wins.len() < other.wins.len() {
+			self.wins.resize(other.wins.len(), 0);
+		}
+		for (wins, &other) in self.wins.iter_mut().zip(&other.wins) {
+			*wins += other;
+		}
+		self.failures += other.failures;
+	}
+
+	/// The most guesses any game took, counting lost games as one more than the most
+	/// guesses allowed.
+	pub fn worst(&self) -> Option<usize> {
+		match self.failures {
+			0 => self.wins.iter().rposition(|&n| n > 0).map(|i| i + 1),
+			_ => Some(self.wins.len() + 1),
+		}
+	}
+
+	/// The number of guesses within which the given fraction of games was won, counting
+	/// lost games as in [`Stats::worst`]. For example `percentile(0.95)` is the 95th
+	/// percentile of solve lengths.
+	pub fn percentile(&self, fraction: f64) -> Option<usize> {
+		if self.games == 0 {
+			return None;
+		}
+		let needed = ((fraction * self.games as f64).ceil() as usize).max(1);
+		let mut seen = 0;
+		for (i, &n) in self.wins.iter().enumerate() {
+			seen += n;
+			if seen >= needed {
+				return Some(i + 1);
+			}
+		}
+		Some(self.wins.len() + 1)
+	}
+
+	/// The mean number of guesses over the games that were won, or 0 if none was.
+	pub fn average(&self) -> f64 {
+		let won: usize = self.wins.iter().sum();
+		let guesses: usize = self.wins.iter().enumerate().map(|(i, &n)| (i + 1) * n).sum();
+		match won {
+			0 => 0.0,
+			won => guesses as f64 / won as f64,
+		}
+	}
+}
+
+impl fmt::Display for Stats {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "games:    {}", self.games)?;
+		writeln!(f, "average:  {:.3}", self.average())?;
+		writeln!(f, "failures: {}", self.failures)?;
+		match self.worst() {
+			Some(worst) if worst > self.wins.len() => writeln!(f, "worst:    X")?,
+			Some(worst) => writeln!(f, "worst:    {}", worst)?,
+			None => {}
+		}
+		writeln!(f, "time:     {:.2?}", self.elapsed)?;
+		let widest = self.wins.iter().copied().chain([self.failures]).max().unwrap_or(0).max(1);
+		let rows = self.wins.iter().enumerate().map(|(i, &n)| ((i + 1).to_string(), n));
+		for (label, n) in rows.chain([("X".to_string(), self.failures)]) {
+			writeln!(f, "{:>2} {:>5} {}", label, n, "#".repeat(n * 50 / widest))?;
+		}
+		Ok(())
+	}
+}
+
+/// How long one game took.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timing {
+	pub elapsed: Duration,
+	/// How long the guesser took over each guess.
+	pub turns: Vec<Duration>,
+	/// Where the guesser's time went, if it keeps count.
+	pub profile: Profile,
+}
+
+/// Times each guess of the guesser it wraps.
+struct Timed<'g, G> {
+	inner: &'g mut G,
+	turns: Vec<Duration>,
+}
+
+impl<G: Guesser> Guesser for Timed<'_, G> {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		let start = Instant::now();
+		let guess = self.inner.guess(history);
+		self.turns.push(start.elapsed());
+		guess
+	}
+}
+
+/// One game of a benchmark, as exported for analysis in other tools.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameRow {
+	pub answer: String,
+	pub algorithm: String,
+	/// The words guessed, in order.
+	pub guesses: Vec<String>,
+	pub solved: bool,
+	pub timing: Timing,
+}
+
+impl GameRow {
+	pub fn new(algorithm: &str, result: &GameResult, timing: &Timing) -> Self {
+		Self {
+			answer: result.answer.clone(),
+			algorithm: algorithm.to_string(),
+			guesses: result.history.iter().map(|guess| guess.word.to_string()).collect(),
+			solved: result.won,
+			timing: timing.clone(),
+		}
+	}
+}
+
+/// Writes `rows` as CSV with a header. The guessed words share a column, separated by
+/// spaces, and so do the times of the turns. Times are in microseconds.
+pub fn to_csv(rows: &[GameRow]) -> String {
+	let mut csv = String::from("answer,algorithm,guesses,solved,words,elapsed_us,turn_us,filtering_us,scoring_us\n");
+	for row in rows {
+		let turns: Vec<String> = row.timing.turns.iter().map(|turn| turn.as_micros().to_string()).collect();
+		csv.push_str(&format!(
+			"{},{},{},{},{},{},{},{},{}\n",
+			row.answer, row.algorithm, row.guesses.len(), row.solved, row.guesses.join(" "),
+			row.timing.elapsed.as_micros(), turns.join(" "),
+			row.timing.profile.filtering.as_micros(), row.timing.profile.scoring.as_micros(),
+		));
+	}
+	csv
+}
+
+/// Writes `rows` as a JSON array with an object per game on a line of its own, with the
+/// same fields as [`to_csv`] but the words and turn times as arrays.
+pub fn to_json(rows: &[GameRow]) -> String {
+	let micros = |duration: &Duration| Value::Number(duration.as_micros() as f64);
+	let mut out = String::from("[");
+	for (i, row) in rows.iter().enumerate() {
+		out.push_str(if i == 0 { "\n" } else { ",\n" });
+		Value::Object(vec![
+			("answer".to_string(), Value::String(row.answer.clone())),
+			("algorithm".to_string(), Value::String(row.algorithm.clone())),
+			("guesses".to_string(), Value::Number(row.guesses.len() as f64)),
+			("solved".to_string(), Value::Bool(row.solved)),
+			("words".to_string(), Value::Array(row.guesses.iter().cloned().map(Value::String).collect())),
+			("elapsed_us".to_string(), micros(&row.timing.elapsed)),
+			("turn_us".to_string(), Value::Array(row.timing.turns.iter().map(micros).collect())),
+			("filtering_us".to_string(), micros(&row.timing.profile.filtering)),
+			("scoring_us".to_string(), micros(&row.timing.profile.scoring)),
+		]).write(&mut out);
+	}
+	out.push_str("\n]\n");
+	out
+}
+
+/// Plays every answer and collects the results. One guesser is built with `new_guesser`
+/// and [reset](Guesser::reset) before each game, so whatever it precomputes is reused.
+/// Stops at the first game in which the guesser makes an invalid guess.
+///
+/// `game` is usually a [`Wordle`](crate::Wordle), but any [`WordGame`] can be played.
+pub fn run<'a, W, G, I, F>(game: &W, answers: I, new_guesser: F) -> Result<Stats, WordleError>
+where
+	W: WordGame,
+	G: Guesser,
+	I: IntoIterator<Item = &'a str>,
+	F: FnOnce() -> G,
+{
+	run_observed(game, answers, new_guesser, |_, _| {})
+}
+
+/// Like [`run`], calling `observe` with each game's result and how long it took.
+fn run_observed<'a, W, G, I, F, O>(game: &W, answers: I, new_guesser: F, mut observe: O) -> Result<Stats, WordleError>
+where
+	W: WordGame,
+	G: Guesser,
+	I: IntoIterator<Item = &'a str>,
+	F: FnOnce() -> G,
+	O: FnMut(&GameResult, &Timing),
+{
+	let start = Instant::now();
+	let mut stats = Stats::new(game.config().max_guesses);
+	let mut guesser = new_guesser();
+	for answer in answers {
+		let game_start = Instant::now();
+		guesser.reset();
+		let before = guesser.profile();
+		let mut timed = Timed { inner: &mut guesser, turns: Vec::new() };
+		let result = game.play(answer, &mut timed)?;
+		let turns = timed.turns;
+		observe(&result, &Timing { elapsed: game_start.elapsed(), turns, profile: guesser.profile().since(before) });
+		stats.record(&result);
+	}
+	stats.elapsed = start.elapsed();
+	Ok(stats)
+}
+
+/// Like [`run`], but spreads the answers over one thread per available core. Each thread
+/// builds its own guesser with `new_guesser`.
+pub fn run_parallel<'a, W, G, I, F>(game: &W, answers: I, new_guesser: F) -> Result<Stats, WordleError>
+where
+	W: WordGame + Sync,
+	G: Guesser,
+	I: IntoIterator<Item = &'a str>,
+	F: Fn() -> G + Sync,
+{
+	run_parallel_observed(game, answers, new_guesser, &|_, _| {})
+}
+
+/// Like [`run_parallel`], also writing a transcript of every game to `recorder`, in the
+/// order the games finish.
+pub fn run_recorded<'a, W, G, I, F, O>(game: &W, answers: I, new_guesser: F, recorder: &Recorder<O>) -> Result<Stats, WordleError>
+where
+	W: WordGame + Sync,
+	G: Guesser,
+	I: IntoIterator<Item = &'a str>,
+	F: Fn() -> G + Sync,
+	O: Write + Send,
+{
+	run_parallel_observed(game, answers, new_guesser, &|result, timing| recorder.record(result, timing.elapsed))
+}
+
+/// Like [`run_parallel`], calling `observe` with each game's result and how long it took,
+/// from whichever thread played it.
+pub fn run_parallel_observed<'a, W, G, I, F>(game: &W, answers: I, new_guesser: F, observe: &(dyn Fn(&GameResult, &Timing) + Sync)) -> Result<Stats, WordleError>
+where
+	W: WordGame + Sync,
+	G: Guesser,
+	I: IntoIterator<Item = &'a str>,
+	F: Fn() -> G + Sync,
+{
+	let start = Instant::now();
+	let answers: Vec<&str> = answers.into_iter().collect();
+	let threads = thread::available_parallelism().map_or(1, |n| n.get());
+	let chunk = answers.len().div_ceil(threads).max(1);
+	let mut stats = thread::scope(|scope| -> Result<Stats, WordleError> {
+		let handles: Vec<_> = answers.chunks(chunk).map(|answers| {
+			let new_guesser = &new_guesser;
+			scope.spawn(move || run_observed(game, answers.iter().copied(), new_guesser, observe))
+		}).collect();
+		let mut stats = Stats::new(game.config().max_guesses);
+		for handle in handles {
+			stats.merge(&handle.join().expect("bench thread panicked")?);
+		}
+		Ok(stats)
+	})?;
+	stats.elapsed = start.elapsed();
+	Ok(stats)
+}
+
+/// How good an opening guess is, on its own and as the start of a strategy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenerReport {
+	/// The opener scored as a first guess, with the given answers as the candidates.
+	pub score: ScoredGuess,
+	/// Games played with the opener followed by the guesser's own guesses.
+	pub stats: Stats,
+}
+
+/// Evaluates `opener` over `answers`, playing each game with the opener and then the
+/// guesses of guessers built by `new_guesser`, spread over threads as in
+/// [`run_parallel`].
+pub fn evaluate_opener<'a, G, I, F>(wordle: &Wordle, opener: &str, answers: I, new_guesser: F) -> Result<OpenerReport, WordleError>
+where
+	G: Guesser,
+	I: IntoIterator<Item = &'a str>,
+	F: Fn() -> G + Sync,
+{
+	let answers: Vec<&str> = answers.into_iter().collect();
+	let stats = run_parallel(wordle, answers.iter().copied(), || OpeningBook::opener(opener, new_guesser()))?;
+	Ok(OpenerReport { score: wordle.score_among(opener, &answers), stats })
+}
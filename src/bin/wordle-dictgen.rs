@@ -0,0 +1,105 @@
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+
+use wordle::dictgen::WordCounter;
+
+const USAGE: &str = "\
+usage: wordle-dictgen [options] [<file>...]
+
+Counts the words of text files, or of standard input if no file is given, and prints
+a dictionary with a word and its count on each line, as read by the wordle engine.
+
+options:
+    --frequencies         the input lists a word and its count on each line, as an
+                          n-gram frequency file does, instead of running text
+    --length <n>          keep words of n letters, 5 by default
+    --min-count <n>       drop words seen fewer than n times, 1 by default
+    --keep-accents        keep accented letters instead of replacing them with their
+                          base letter
+    --output <file>       write the dictionary to a file instead of standard output";
+
+struct Options {
+    frequencies: bool,
+    length: usize,
+    min_count: usize,
+    keep_accents: bool,
+    output: Option<String>,
+    inputs: Vec<String>,
+}
+
+fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Options, String> {
+    let mut options = Options { frequencies: false, length: 5, min_count: 1, keep_accents: false, output: None, inputs: Vec::new() };
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--frequencies" => options.frequencies = true,
+            "--length" => {
+                let n = value()?;
+                options.length = n.parse().ok().filter(|&n| n > 0).ok_or_else(|| format!("invalid --length '{}'", n))?;
+            }
+            "--min-count" => {
+                let n = value()?;
+                options.min_count = n.parse().map_err(|_| format!("invalid --min-count '{}'", n))?;
+            }
+            "--keep-accents" => options.keep_accents = true,
+            "--output" => options.output = Some(value()?),
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option '{}'", flag)),
+            _ => options.inputs.push(arg),
+        }
+    }
+    Ok(options)
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let mut counter = WordCounter::new(options.length).with_accents(options.keep_accents);
+    let mut add = |name: &str, text: &str| {
+        if options.frequencies {
+            for line in counter.add_frequencies(text) {
+                eprintln!("{}:{}: skipped a line without a count", name, line);
+            }
+        }
+        else {
+            counter.add_text(text);
+        }
+    };
+    if options.inputs.is_empty() {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text).map_err(|e| format!("cannot read standard input: {}", e))?;
+        add("<stdin>", &text);
+    }
+    for path in &options.inputs {
+        let text = fs::read_to_string(path).map_err(|e| format!("cannot read '{}': {}", path, e))?;
+        add(path, &text);
+    }
+    if counter.is_empty() {
+        return Err(format!("no words of {} letters found", options.length));
+    }
+    let dictionary = counter.write(options.min_count);
+    match &options.output {
+        Some(path) => fs::write(path, dictionary).map_err(|e| format!("cannot write '{}': {}", path, e)),
+        None => {
+            print!("{}", dictionary);
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let options = match parse(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    if let Err(e) = run(&options) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
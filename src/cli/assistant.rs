@@ -0,0 +1,94 @@
+use std::io::{self, BufRead, Write};
+
+use wordle::algorithms::Entropy;
+use wordle::{mask, Constraints, Correctness, Guess, Wordle};
+
+const SUGGESTIONS: usize = 5;
+
+/// What is already known when joining a game part of the way through, as given on the
+/// command line; see [`Constraints::parse`].
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    pub green: String,
+    pub yellow: String,
+    pub gray: String,
+}
+
+/// Helps solve a game played elsewhere, such as the official Wordle.
+///
+/// Each line of input is a guess followed by the feedback it got, e.g. "crane GYBBY",
+/// where G is green, Y is yellow and B is gray (or in any other style [`mask::parse`]
+/// accepts, such as "crane CMWWM"). After each line the best next guesses
+/// are printed. Letters already known from `options` narrow down the answers from the
+/// start.
+pub fn run(wordle: &Wordle, options: &Options) -> Result<(), String> {
+    let constraints = Constraints::parse(&options.green, &options.yellow, &options.gray, wordle.config().word_len)?;
+    let wordle = &constraints.narrow(wordle);
+    let mut solver = Entropy::new(wordle);
+    let mut history = Vec::new();
+    if !constraints.is_empty() && report(wordle, &mut solver, &history) {
+        return Ok(());
+    }
+    println!("Enter each guess and its feedback, e.g. 'crane GYBBY'.");
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush().expect("stdout is writable");
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).expect("stdin is readable") == 0 {
+            break;
+        }
+        let guess = match parse_line(&line, wordle.config().word_len) {
+            Some(guess) => guess,
+            None => {
+                println!("expected a {}-letter guess and feedback like 'crane GYBBY'", wordle.config().word_len);
+                continue;
+            }
+        };
+        if guess.mask.iter().all(|&c| c == Correctness::Correct) {
+            println!("Solved!");
+            break;
+        }
+        history.push(guess);
+        if report(wordle, &mut solver, &history) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Prints the best next guesses, or the answer if it is known. Returns whether there is
+/// nothing left to solve.
+fn report(wordle: &Wordle, solver: &mut Entropy, history: &[Guess]) -> bool {
+    let suggestions = solver.suggest_top(history, SUGGESTIONS);
+    if !solver.remaining().is_empty() && wordle.candidates(history).next().is_none() {
+        println!("No answer-list word matches what is known, so the answer is another dictionary word.");
+    }
+    match solver.remaining() {
+        [] => {
+            println!("No word in the dictionary matches what is known.");
+            true
+        }
+        [only] => {
+            println!("The answer is '{}'.", only);
+            true
+        }
+        remaining => {
+            println!("{} candidates remain. Best next guesses:", remaining.len());
+            for (word, bits) in suggestions {
+                println!("  {} ({:.2} bits)", word, bits);
+            }
+            false
+        }
+    }
+}
+
+fn parse_line(line: &str, word_len: usize) -> Option<Guess<'static>> {
+    let mut parts = line.split_whitespace();
+    let word = parts.next()?.to_lowercase();
+    let mask = mask::parse(parts.next()?)?;
+    if word.chars().count() != word_len || mask.len() != word_len || parts.next().is_some() {
+        return None;
+    }
+    Some(Guess { word: word.into(), mask })
+}
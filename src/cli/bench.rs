@@ -0,0 +1,260 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use wordle::algorithms::{self, Algorithm, Cached, Entropy, MoveCache, OpeningBook};
+use wordle::replay::Recorder;
+use wordle::bench::{self, GameRow};
+use wordle::{render, GameResult, Guesser, PatternMatrix, Profile, Wordle};
+
+/// How many turns of guesses are shared between games. Two turns cover the expensive
+/// searches over the whole dictionary.
+const CACHED_TURNS: usize = 2;
+
+/// How wide the progress bar is drawn.
+const BAR_WIDTH: usize = 30;
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    /// The registered name of the algorithm to benchmark.
+    pub algorithm: String,
+    /// Only play the first this many answers.
+    pub max_games: Option<usize>,
+    /// Play the answers listed in this file, one per line, instead of the answer list.
+    pub answers_file: Option<String>,
+    /// Only play answers starting with this prefix.
+    pub filter: Option<String>,
+    /// Write a transcript of every game to this file.
+    pub record: Option<String>,
+    /// Write a row per game to this file, as CSV or JSON by its extension.
+    pub output: Option<String>,
+    /// Print the guesses of every game as it finishes.
+    pub verbose: bool,
+    /// Play every game until it is solved, however many guesses it takes.
+    pub unlimited: bool,
+    /// Open every game with these guesses before the algorithm takes over.
+    pub opener: Vec<String>,
+    /// Look patterns up in the matrix cached in this file, computing and saving it first
+    /// if the file does not exist yet.
+    pub matrix: Option<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            algorithm: "entropy".to_string(),
+            max_games: None,
+            answers_file: None,
+            filter: None,
+            record: None,
+            output: None,
+            verbose: false,
+            unlimited: false,
+            opener: Vec::new(),
+            matrix: None,
+        }
+    }
+}
+
+/// Benchmarks the chosen algorithm on the answer list. The first guesses of algorithms
+/// that depend on the history alone are computed once and reused.
+pub fn run(wordle: &Wordle, options: &Options, color: bool) -> Result<(), String> {
+    let wordle = &wordle.clone().with_unlimited_guesses(options.unlimited);
+    let algorithm = algorithms::algorithm(&options.algorithm)
+        .ok_or_else(|| format!("unknown algorithm '{}'", options.algorithm))?;
+    check_opener(wordle, &options.opener)?;
+    let export: Option<fn(&[GameRow]) -> String> = match options.output.as_deref() {
+        Some(path) if path.ends_with(".csv") => Some(bench::to_csv),
+        Some(path) if path.ends_with(".json") => Some(bench::to_json),
+        Some(path) => return Err(format!("cannot tell the format of '{}'; use .csv or .json", path)),
+        None => None,
+    };
+    let listed = match &options.answers_file {
+        Some(path) => Some(read_answers(wordle, path)?),
+        None => None,
+    };
+    let wordle = &match &listed {
+        Some(listed) => including(wordle, listed),
+        None => wordle.clone(),
+    };
+    let answers: Vec<&str> = match &listed {
+        Some(listed) => listed.iter().map(String::as_str).collect(),
+        None => wordle.answers().to_vec(),
+    };
+    let answers: Vec<&str> = answers.into_iter()
+        .filter(|answer| options.filter.as_deref().is_none_or(|prefix| answer.starts_with(prefix)))
+        .take(options.max_games.unwrap_or(usize::MAX))
+        .collect();
+    // the bar would garble the games printed by --verbose
+    let progress = (!options.verbose && io::stderr().is_terminal()).then(|| Progress::new(answers.len()));
+    let matrix = match &options.matrix {
+        Some(path) => Some(Arc::new(pattern_matrix(wordle, algorithm, path)?)),
+        None => None,
+    };
+    let build = || -> Box<dyn Guesser> {
+        match (&matrix, algorithm.name) {
+            (Some(matrix), "prior") => Box::new(Entropy::new(wordle).with_frequency_prior().with_matrix(Arc::clone(matrix))),
+            (Some(matrix), _) => Box::new(Entropy::new(wordle).with_matrix(Arc::clone(matrix))),
+            (None, _) => (algorithm.build)(wordle),
+        }
+    };
+    let cache = move_cache(algorithm);
+    let new_guesser = || OpeningBook::new(options.opener.iter().cloned(), Cached::new(build(), &cache));
+    let recorder = match &options.record {
+        Some(path) => Some(Recorder::create(path).map_err(|e| format!("cannot create '{}': {}", path, e))?),
+        None => None,
+    };
+    let rows = Mutex::new(Vec::new());
+    let profile = Mutex::new(Profile::default());
+    let stats = bench::run_parallel_observed(wordle, answers, new_guesser, &|result, timing| {
+        *profile.lock().expect("no thread panics while holding the lock") += timing.profile;
+        if export.is_some() {
+            rows.lock().expect("no thread panics while holding the lock").push(GameRow::new(&options.algorithm, result, timing));
+        }
+        if let Some(recorder) = &recorder {
+            recorder.record(result, timing.elapsed);
+        }
+        if options.verbose {
+            // one print per game, so games finishing on other threads don't interleave
+            print!("{}", show(result, color));
+        }
+        if let Some(progress) = &progress {
+            progress.record(result);
+        }
+    }).map_err(|e| e.to_string())?;
+    if progress.is_some() {
+        eprintln!();
+    }
+    if let Some(recorder) = recorder {
+        recorder.finish().map_err(|e| format!("cannot write transcripts: {}", e))?;
+    }
+    if let (Some(export), Some(path)) = (export, &options.output) {
+        // games finish in any order on the threads
+        let mut rows = rows.into_inner().expect("no thread panics while holding the lock");
+        rows.sort_by(|a, b| a.answer.cmp(&b.answer));
+        fs::write(path, export(&rows)).map_err(|e| format!("cannot write '{}': {}", path, e))?;
+    }
+    match &options.opener[..] {
+        [] => println!("{}", options.algorithm),
+        opener => println!("{} after {}", options.algorithm, opener.join(", ")),
+    }
+    print!("{}", stats);
+    let profile = profile.into_inner().expect("no thread panics while holding the lock");
+    // summed over the threads, and only counted by some algorithms
+    if profile != Profile::default() {
+        println!("filtering: {:.2?}", profile.filtering);
+        println!("scoring:   {:.2?}", profile.scoring);
+    }
+    Ok(())
+}
+
+/// Loads the pattern matrix cached at `path`, or computes it for `wordle` and saves it
+/// there if there is no such file yet.
+fn pattern_matrix(wordle: &Wordle, algorithm: &Algorithm, path: &str) -> Result<PatternMatrix, String> {
+    if !matches!(algorithm.name, "entropy" | "prior") {
+        return Err("--matrix only works with the entropy and prior algorithms".to_string());
+    }
+    if !Path::new(path).exists() {
+        eprintln!("computing the pattern matrix into '{}'", path);
+        let matrix = PatternMatrix::compute(wordle.words(), wordle.answers(), wordle.duplicate_rule());
+        matrix.save(path).map_err(|e| format!("cannot write '{}': {}", path, e))?;
+        return Ok(matrix);
+    }
+    let matrix = PatternMatrix::load(path).map_err(|e| format!("cannot read '{}': {}", path, e))?;
+    if matrix.rule() != wordle.duplicate_rule() || matrix.word_len() != wordle.config().word_len {
+        return Err(format!("'{}' was computed for other rules; delete it to compute it again", path));
+    }
+    Ok(matrix)
+}
+
+/// A cache for the first moves of `algorithm`, which remembers nothing if its guesses do
+/// not depend on the history alone.
+pub fn move_cache(algorithm: &Algorithm) -> Arc<MoveCache> {
+    MoveCache::new(match algorithm.deterministic {
+        true => CACHED_TURNS,
+        false => 0,
+    })
+}
+
+/// Checks that every guess of an opener may be played.
+pub fn check_opener(wordle: &Wordle, opener: &[String]) -> Result<(), String> {
+    match opener.iter().find(|word| !wordle.is_allowed(word, &[])) {
+        Some(word) => Err(format!("opener '{}' is not in the dictionary", word)),
+        None => Ok(()),
+    }
+}
+
+/// Reads a list of answers, one per line, checking that each is in the dictionary.
+fn read_answers(wordle: &Wordle, path: &str) -> Result<Vec<String>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("cannot read '{}': {}", path, e))?;
+    text.lines()
+        .map(|line| line.trim().to_lowercase())
+        .filter(|line| !line.is_empty())
+        .map(|answer| match wordle.contains(&answer) {
+            true => Ok(answer),
+            false => Err(format!("'{}' in '{}' is not in the dictionary", answer, path)),
+        })
+        .collect()
+}
+
+/// A copy of `wordle` whose answer list also has the `listed` answers, so that guessers
+/// filtering the answer list can find answers that are only in the dictionary.
+fn including(wordle: &Wordle, listed: &[String]) -> Wordle {
+    let known: HashSet<&str> = wordle.answers().iter().copied().collect();
+    let mut extra: Vec<&str> = listed.iter().map(String::as_str).filter(|answer| !known.contains(answer)).collect();
+    extra.sort_unstable();
+    extra.dedup();
+    if extra.is_empty() {
+        return wordle.clone();
+    }
+    wordle.clone()
+        .with_answers(wordle.answers().iter().copied().chain(extra))
+        .expect("dictionary words are valid answers")
+}
+
+/// A progress bar on stderr, redrawn as each game finishes: games done, the running
+/// average and the estimated time left.
+struct Progress {
+    total: usize,
+    start: Instant,
+    /// Games done, games won and the guesses they took.
+    counts: Mutex<(usize, usize, usize)>,
+}
+
+impl Progress {
+    fn new(total: usize) -> Self {
+        Self { total, start: Instant::now(), counts: Mutex::new((0, 0, 0)) }
+    }
+
+    fn record(&self, result: &GameResult) {
+        let mut counts = self.counts.lock().expect("no thread panics while holding the lock");
+        let (done, won, guesses) = &mut *counts;
+        *done += 1;
+        if let Some(n) = result.solved_in() {
+            *won += 1;
+            *guesses += n;
+        }
+        let filled = *done * BAR_WIDTH / self.total.max(1);
+        let left = self.start.elapsed().mul_f64((self.total - *done) as f64 / *done as f64);
+        let average = match *won {
+            0 => "-".to_string(),
+            won => format!("{:.3}", *guesses as f64 / won as f64),
+        };
+        let mut stderr = io::stderr().lock();
+        let _ = write!(
+            stderr,
+            "\r[{}{}] {}/{} average {} eta {}s ",
+            "#".repeat(filled), " ".repeat(BAR_WIDTH - filled), done, self.total, average,
+            left.as_secs()
+        );
+        let _ = stderr.flush();
+    }
+}
+
+fn show(result: &GameResult, color: bool) -> String {
+    let turns = result.solved_in().map_or_else(|| "X".to_string(), |n| n.to_string());
+    format!("{} {}\n{}\n", result.answer, turns, render::history(&result.history, color))
+}
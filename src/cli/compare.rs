@@ -0,0 +1,77 @@
+use std::fs;
+use std::time::Duration;
+
+use wordle::algorithms::{self, Algorithm, Cached, OpeningBook};
+use wordle::bench::{self, Stats};
+use wordle::Wordle;
+
+use super::bench::{check_opener, move_cache};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    /// The algorithms to compare, or every registered one if empty.
+    pub algorithms: Vec<String>,
+    /// Only play the first this many answers.
+    pub max_games: Option<usize>,
+    /// Also write the table to this CSV file.
+    pub csv: Option<String>,
+    /// Open every game with these guesses before each algorithm takes over.
+    pub opener: Vec<String>,
+}
+
+/// Benchmarks several algorithms on the same answers and prints them ranked by failures,
+/// then by average guesses.
+pub fn run(wordle: &Wordle, options: &Options) -> Result<(), String> {
+    let chosen: Vec<&Algorithm> = match &options.algorithms[..] {
+        [] => algorithms::ALGORITHMS.iter().collect(),
+        names => names.iter()
+            .map(|name| algorithms::algorithm(name).ok_or_else(|| format!("unknown algorithm '{}'", name)))
+            .collect::<Result<_, _>>()?,
+    };
+    check_opener(wordle, &options.opener)?;
+    let answers = &wordle.answers()[..options.max_games.unwrap_or(usize::MAX).min(wordle.answers().len())];
+    let mut results = Vec::new();
+    for algorithm in chosen {
+        eprintln!("running {}...", algorithm.name);
+        let cache = move_cache(algorithm);
+        let stats = bench::run_parallel(wordle, answers.iter().copied(), || {
+            OpeningBook::new(options.opener.iter().cloned(), Cached::new((algorithm.build)(wordle), &cache))
+        })
+            .map_err(|e| format!("{}: {}", algorithm.name, e))?;
+        results.push((algorithm.name, stats));
+    }
+    results.sort_by(|(_, a), (_, b)| a.failures.cmp(&b.failures).then(a.average().total_cmp(&b.average())));
+    println!("{:<4} {:<10} {:>7} {:>8} {:>4} {:>5} {:>10}", "rank", "algorithm", "average", "failures", "p95", "worst", "per game");
+    for (rank, (name, stats)) in results.iter().enumerate() {
+        println!(
+            "{:<4} {:<10} {:>7.3} {:>8} {:>4} {:>5} {:>10}",
+            rank + 1, name, stats.average(), stats.failures,
+            turns(stats.percentile(0.95), stats), turns(stats.worst(), stats), format!("{:.2?}", per_game(stats)),
+        );
+    }
+    if let Some(path) = &options.csv {
+        let mut csv = String::from("algorithm,games,average,failures,p95,worst,seconds_per_game\n");
+        for (name, stats) in &results {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{}\n",
+                name, stats.games, stats.average(), stats.failures,
+                turns(stats.percentile(0.95), stats), turns(stats.worst(), stats), per_game(stats).as_secs_f64(),
+            ));
+        }
+        fs::write(path, csv).map_err(|e| format!("cannot write '{}': {}", path, e))?;
+    }
+    Ok(())
+}
+
+/// A number of guesses, written as X if it means the game was lost.
+fn turns(n: Option<usize>, stats: &Stats) -> String {
+    match n {
+        Some(n) if n > stats.wins.len() => "X".to_string(),
+        Some(n) => n.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn per_game(stats: &Stats) -> Duration {
+    stats.elapsed / stats.games.max(1) as u32
+}
@@ -0,0 +1,35 @@
+use wordle::algorithms::{self, Cached};
+use wordle::bench;
+use wordle::Wordle;
+
+use super::bench::move_cache;
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub opener: String,
+    /// The registered name of the algorithm making the guesses after the opener.
+    pub algorithm: String,
+    /// Only play the first this many answers.
+    pub max_games: Option<usize>,
+}
+
+/// Scores an opener on the answer list and benchmarks it with the chosen follow-up.
+pub fn run(wordle: &Wordle, options: &Options) -> Result<(), String> {
+    let algorithm = algorithms::algorithm(&options.algorithm)
+        .ok_or_else(|| format!("unknown algorithm '{}'", options.algorithm))?;
+    let opener = options.opener.to_lowercase();
+    if !wordle.contains(&opener) {
+        return Err(format!("'{}' is not in the dictionary", opener));
+    }
+    let answers = &wordle.answers()[..options.max_games.unwrap_or(usize::MAX).min(wordle.answers().len())];
+    let cache = move_cache(algorithm);
+    let report = bench::evaluate_opener(wordle, &opener, answers.iter().copied(), || Cached::new((algorithm.build)(wordle), &cache))
+        .map_err(|e| e.to_string())?;
+    println!("opener:             {}", opener);
+    println!("information:        {:.3} bits", report.score.bits);
+    println!("expected remaining: {:.3}", report.score.expected_remaining);
+    println!("worst bucket:       {}", report.score.worst_remaining);
+    println!("follow-up:          {}", options.algorithm);
+    print!("{}", report.stats);
+    Ok(())
+}
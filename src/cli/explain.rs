@@ -0,0 +1,62 @@
+use wordle::{algorithms, render, Guess, Guesser, ScoredGuess, Wordle};
+
+/// How many of the best alternatives are shown for each turn.
+const ALTERNATIVES: usize = 3;
+
+/// Plays the named algorithm against `answer` and shows its reasoning turn by turn: how
+/// many candidates were left, how much the guess was expected to tell and actually told,
+/// and the best alternatives by expected information.
+pub fn run(wordle: &Wordle, answer: &str, algorithm: &str, color: bool) -> Result<(), String> {
+    let build = algorithms::algorithm(algorithm)
+        .ok_or_else(|| format!("unknown algorithm '{}'", algorithm))?
+        .build;
+    let mut turns = Vec::new();
+    let result = wordle.play(answer, Explaining { wordle, inner: build(wordle), turns: &mut turns })
+        .map_err(|e| e.to_string())?;
+    for (i, (guess, turn)) in result.history.iter().zip(&turns).enumerate() {
+        let left = wordle.candidates(&result.history[..=i]).count();
+        let gained = (turn.candidates as f64 / left.max(1) as f64).log2();
+        println!(
+            "{}. {}  {} -> {} candidates, {:.2} bits (expected {:.2})",
+            i + 1, render::guess(guess, color), turn.candidates, left, gained, turn.chosen.bits
+        );
+        let alternatives: Vec<String> = turn.alternatives.iter()
+            .filter(|alternative| alternative.word != guess.word)
+            .take(ALTERNATIVES)
+            .map(|alternative| format!("{} {:.2}", alternative.word, alternative.bits))
+            .collect();
+        if !alternatives.is_empty() {
+            println!("   alternatives: {}", alternatives.join(", "));
+        }
+    }
+    match result.solved_in() {
+        Some(n) => println!("solved in {}", n),
+        None => println!("failed to find '{}'", result.answer),
+    }
+    Ok(())
+}
+
+/// What a guesser faced on one turn.
+struct Turn {
+    candidates: usize,
+    chosen: ScoredGuess,
+    alternatives: Vec<ScoredGuess>,
+}
+
+struct Explaining<'a, G> {
+    wordle: &'a Wordle,
+    inner: G,
+    turns: &'a mut Vec<Turn>,
+}
+
+impl<G: Guesser> Guesser for Explaining<'_, G> {
+    fn guess(&mut self, history: &[Guess]) -> String {
+        let guess = self.inner.guess(history);
+        self.turns.push(Turn {
+            candidates: self.wordle.candidates(history).count(),
+            chosen: self.wordle.score(&guess, history),
+            alternatives: self.wordle.hint(history, ALTERNATIVES + 1),
+        });
+        guess
+    }
+}
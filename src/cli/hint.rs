@@ -0,0 +1,24 @@
+use wordle::{mask, Guess, Wordle};
+
+const SUGGESTIONS: usize = 5;
+
+/// Prints the best next guesses for a history given as `guess:feedback` pairs.
+pub fn run(wordle: &Wordle, history: &[String]) -> Result<(), String> {
+    let history = history.iter().map(|entry| {
+        let (word, feedback) = entry.split_once(':')
+            .ok_or_else(|| format!("expected guess:feedback, got '{}'", entry))?;
+        let word_len = wordle.config().word_len;
+        let mask = mask::parse(feedback)
+            .filter(|mask| mask.len() == word_len && word.chars().count() == word_len)
+            .ok_or_else(|| format!("invalid feedback '{}'", entry))?;
+        Ok(Guess { word: word.to_lowercase().into(), mask })
+    }).collect::<Result<Vec<_>, String>>()?;
+    for hint in wordle.hint(&history, SUGGESTIONS) {
+        println!(
+            "{} ({:.2} bits, {:.1} left on average, at most {})",
+            hint.word, hint.bits, hint.expected_remaining, hint.worst_remaining
+        );
+    }
+    println!("{} candidates remain", wordle.candidates(&history).count());
+    Ok(())
+}
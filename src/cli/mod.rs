@@ -0,0 +1,438 @@
+pub mod assistant;
+pub mod bench;
+pub mod compare;
+pub mod evaluate;
+pub mod explain;
+pub mod hint;
+pub mod play;
+pub mod replay;
+pub mod stats;
+pub mod tui;
+
+use std::env;
+use std::io::{self, IsTerminal};
+
+use wordle::daily::Date;
+use wordle::Wordle;
+
+pub const USAGE: &str = "\
+usage: wordle <command> [options]
+
+commands:
+    play [options]            play a game in the terminal
+        --adversarial         play against a host that dodges your guesses like
+                              Absurdle
+        --share               print the emoji grid for sharing afterwards
+        --daily               play today's puzzle, the same for everyone
+        --date <YYYY-MM-DD>   play the daily puzzle of another day
+        --save <file>         save the game to a file on quitting early, and
+                              resume it from there next time
+    tui                       play on a full-screen board with a keyboard and the
+                              solver's suggestions
+    stats                     show your record over the games you played
+    solve [options]           get suggestions while playing elsewhere
+        --green <pattern>     letters known in place, e.g. '_r_ne'
+        --yellow <letters>    letters known to be elsewhere in the word
+        --gray <letters>      letters known not to be in the word
+    bench [options]           benchmark a guessing algorithm on the answer list
+        --algorithm <name>    entropy (default), or another from 'algorithms'
+        --max-games <n>       only play the first n answers
+        --answers-file <file> play the answers listed in a file, one per line
+        --filter <prefix>     only play answers starting with the prefix
+        --record <file>       write a transcript of every game to a JSONL file
+        --output <file>       write a row per game to a .csv or .json file
+        --verbose             show the guesses of every game
+        --unlimited           keep guessing until solved instead of stopping
+                              after six guesses
+        --opener <a,b,..>     open every game with these guesses, then let the
+                              algorithm take over
+        --matrix <file>       look patterns up in a matrix cached in the file,
+                              computing it first if there is none (entropy and
+                              prior only)
+    compare [options]         rank several algorithms on the same answers
+        --algorithms <a,b,..> the algorithms to compare, all by default
+        --max-games <n>       only play the first n answers
+        --csv <file>          also write the table to a CSV file
+        --opener <a,b,..>     open every game with these guesses
+    evaluate <opener> [options]
+                              score an opening guess and play every answer with it
+        --algorithm <name>    the algorithm guessing after the opener, entropy
+                              by default
+        --max-games <n>       only play the first n answers
+    explain <answer> [--algorithm <name>]
+                              show the reasoning of an algorithm turn by turn
+    hint <guess:feedback>...  suggest next guesses, e.g. 'hint crane:GYBBY'
+    algorithms                list the guessing algorithms
+    replay <file>             check and show the games recorded by bench --record
+    serve [--addr <host:port>]
+                              serve games over a JSON HTTP API, on
+                              127.0.0.1:8080 by default
+    help                      show this message
+
+options:
+    --no-color                show feedback as letters instead of colors, as
+                              does setting NO_COLOR
+    --dictionary <file>       play with the words of a dictionary file, one per
+                              line with an optional frequency, instead of the
+                              embedded ones";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Play(play::Options),
+    Tui,
+    Stats,
+    Solve(assistant::Options),
+    Bench(bench::Options),
+    Compare(compare::Options),
+    Evaluate(evaluate::Options),
+    Explain { answer: String, algorithm: String },
+    Hint { history: Vec<String> },
+    Replay { path: String },
+    Serve { addr: String },
+    Algorithms,
+    Help,
+}
+
+/// Parses the command line, not including the program name.
+pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let command = match args.next().as_deref() {
+        Some("play") => {
+            let mut options = play::Options::default();
+            while let Some(flag) = args.next() {
+                match flag.as_str() {
+                    "--adversarial" => options.adversarial = true,
+                    "--share" => options.share = true,
+                    "--daily" => options.daily = Some(options.daily.unwrap_or_else(Date::today)),
+                    "--date" => {
+                        let date = args.next().ok_or_else(|| format!("{} needs a value", flag))?;
+                        options.daily = Some(date.parse()?);
+                    }
+                    "--save" => options.save = Some(args.next().ok_or_else(|| format!("{} needs a value", flag))?),
+                    _ => return Err(format!("unknown option '{}'", flag)),
+                }
+            }
+            if options.adversarial && options.daily.is_some() {
+                return Err("an adversarial game has no daily puzzle".to_string());
+            }
+            if options.adversarial && options.save.is_some() {
+                return Err("an adversarial game cannot be saved".to_string());
+            }
+            return Ok(Command::Play(options));
+        }
+        Some("tui") => Command::Tui,
+        Some("stats") => Command::Stats,
+        Some("solve") => {
+            let mut options = assistant::Options::default();
+            while let Some(flag) = args.next() {
+                let value = args.next().ok_or_else(|| format!("{} needs a value", flag))?;
+                match flag.as_str() {
+                    "--green" => options.green = value,
+                    "--yellow" => options.yellow = value,
+                    "--gray" => options.gray = value,
+                    _ => return Err(format!("unknown option '{}'", flag)),
+                }
+            }
+            return Ok(Command::Solve(options));
+        }
+        Some("bench") => {
+            let mut options = bench::Options::default();
+            while let Some(flag) = args.next() {
+                let mut value = || args.next().ok_or_else(|| format!("{} needs a value", flag));
+                match flag.as_str() {
+                    "--algorithm" => options.algorithm = value()?,
+                    "--max-games" => options.max_games = Some(max_games(value()?)?),
+                    "--answers-file" => options.answers_file = Some(value()?),
+                    "--filter" => options.filter = Some(value()?.to_lowercase()),
+                    "--record" => options.record = Some(value()?),
+                    "--output" => options.output = Some(value()?),
+                    "--verbose" => options.verbose = true,
+                    "--unlimited" => options.unlimited = true,
+                    "--opener" => options.opener = opener(&value()?),
+                    "--matrix" => options.matrix = Some(value()?),
+                    _ => return Err(format!("unknown option '{}'", flag)),
+                }
+            }
+            return Ok(Command::Bench(options));
+        }
+        Some("compare") => {
+            let mut options = compare::Options::default();
+            while let Some(flag) = args.next() {
+                let mut value = || args.next().ok_or_else(|| format!("{} needs a value", flag));
+                match flag.as_str() {
+                    "--algorithms" => options.algorithms = value()?.split(',').map(String::from).collect(),
+                    "--max-games" => options.max_games = Some(max_games(value()?)?),
+                    "--csv" => options.csv = Some(value()?),
+                    "--opener" => options.opener = opener(&value()?),
+                    _ => return Err(format!("unknown option '{}'", flag)),
+                }
+            }
+            return Ok(Command::Compare(options));
+        }
+        Some("evaluate") => {
+            let opener = args.next().ok_or("evaluate needs an opener")?;
+            let mut options = evaluate::Options { opener, algorithm: "entropy".to_string(), max_games: None };
+            while let Some(flag) = args.next() {
+                let mut value = || args.next().ok_or_else(|| format!("{} needs a value", flag));
+                match flag.as_str() {
+                    "--algorithm" => options.algorithm = value()?,
+                    "--max-games" => options.max_games = Some(max_games(value()?)?),
+                    _ => return Err(format!("unknown option '{}'", flag)),
+                }
+            }
+            return Ok(Command::Evaluate(options));
+        }
+        Some("explain") => {
+            let answer = args.next().ok_or("explain needs an answer")?;
+            let algorithm = match args.next().as_deref() {
+                Some("--algorithm") => args.next().ok_or("--algorithm needs a value")?,
+                Some(other) => return Err(format!("unknown option '{}'", other)),
+                None => "entropy".to_string(),
+            };
+            Command::Explain { answer, algorithm }
+        }
+        Some("hint") => return Ok(Command::Hint { history: args.collect() }),
+        Some("replay") => match args.next() {
+            Some(path) => Command::Replay { path },
+            None => return Err("replay needs a file".to_string()),
+        },
+        Some("serve") => match args.next().as_deref() {
+            Some("--addr") => Command::Serve { addr: args.next().ok_or("--addr needs a value")? },
+            Some(other) => return Err(format!("unknown option '{}'", other)),
+            None => Command::Serve { addr: "127.0.0.1:8080".to_string() },
+        },
+        Some("algorithms") => Command::Algorithms,
+        Some("help") | Some("--help") | Some("-h") | None => Command::Help,
+        Some(other) => return Err(format!("unknown command '{}'", other)),
+    };
+    match args.next() {
+        Some(extra) => Err(format!("unexpected argument '{}'", extra)),
+        None => Ok(command),
+    }
+}
+
+/// Parses the value of `--max-games`.
+fn max_games(value: String) -> Result<usize, String> {
+    value.parse().map_err(|_| format!("invalid --max-games '{}'", value))
+}
+
+/// Splits an opener such as "salet,courd" into its guesses.
+fn opener(words: &str) -> Vec<String> {
+    words.split(',').map(|word| word.trim().to_lowercase()).filter(|word| !word.is_empty()).collect()
+}
+
+/// Removes `--dictionary <file>` from the arguments and loads the words from the file,
+/// or else uses the embedded dictionary, if this build has one.
+pub fn take_wordle(args: &mut Vec<String>) -> Result<Wordle, String> {
+    let path = match args.iter().position(|arg| arg == "--dictionary") {
+        Some(i) if i + 1 < args.len() => Some(args.drain(i..i + 2).nth(1).expect("the flag has a value")),
+        Some(_) => return Err("--dictionary needs a value".to_string()),
+        None => None,
+    };
+    match path {
+        Some(path) => Wordle::from_dictionary(&path).map_err(|e| format!("cannot load '{}': {}", path, e)),
+        None => embedded(),
+    }
+}
+
+#[cfg(any(feature = "full-dictionary", feature = "answers-dictionary"))]
+fn embedded() -> Result<Wordle, String> {
+    Ok(Wordle::new())
+}
+
+#[cfg(not(any(feature = "full-dictionary", feature = "answers-dictionary")))]
+fn embedded() -> Result<Wordle, String> {
+    Err("this build has no embedded dictionary; pass --dictionary <file>".to_string())
+}
+
+/// Removes every `--no-color` from the arguments, and reports whether feedback should be
+/// shown in color: only on a terminal, and unless disabled by the flag or by `NO_COLOR`.
+pub fn take_color(args: &mut Vec<String>) -> bool {
+    let before = args.len();
+    args.retain(|arg| arg != "--no-color");
+    args.len() == before && env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assistant, bench, compare, evaluate, parse, play, take_color, take_wordle, Command};
+    use wordle::daily::Date;
+    use wordle::Wordle;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn subcommands() {
+        assert_eq!(parse(args("play")), Ok(Command::Play(play::Options::default())));
+        assert_eq!(parse(args("tui")), Ok(Command::Tui));
+        assert_eq!(parse(args("stats")), Ok(Command::Stats));
+        assert_eq!(parse(args("solve")), Ok(Command::Solve(assistant::Options::default())));
+        assert_eq!(
+            parse(args("solve --green _r_ne --yellow a --gray stdl")),
+            Ok(Command::Solve(assistant::Options {
+                green: "_r_ne".to_string(),
+                yellow: "a".to_string(),
+                gray: "stdl".to_string(),
+            }))
+        );
+        assert!(parse(args("solve --gray")).is_err());
+        assert_eq!(parse(args("")), Ok(Command::Help));
+        assert_eq!(parse(args("replay games.jsonl")), Ok(Command::Replay { path: "games.jsonl".to_string() }));
+        assert!(parse(args("replay")).is_err());
+        assert_eq!(parse(args("algorithms")), Ok(Command::Algorithms));
+        assert_eq!(
+            parse(args("explain cigar --algorithm minimax")),
+            Ok(Command::Explain { answer: "cigar".to_string(), algorithm: "minimax".to_string() })
+        );
+        assert!(parse(args("explain")).is_err());
+        assert_eq!(parse(args("serve")), Ok(Command::Serve { addr: "127.0.0.1:8080".to_string() }));
+        assert_eq!(parse(args("serve --addr 0.0.0.0:80")), Ok(Command::Serve { addr: "0.0.0.0:80".to_string() }));
+        assert!(parse(args("fly")).is_err());
+        assert!(parse(args("play now")).is_err());
+    }
+
+    #[test]
+    fn play_options() {
+        assert_eq!(
+            parse(args("play --share --adversarial")),
+            Ok(Command::Play(play::Options { adversarial: true, share: true, daily: None, save: None }))
+        );
+        assert_eq!(
+            parse(args("play --save game.json")),
+            Ok(Command::Play(play::Options { save: Some("game.json".to_string()), ..Default::default() }))
+        );
+        assert!(parse(args("play --adversarial --save game.json")).is_err());
+        assert_eq!(
+            parse(args("play --date 2022-01-01")),
+            Ok(Command::Play(play::Options { daily: Date::new(2022, 1, 1), ..Default::default() }))
+        );
+        assert!(parse(args("play --date tomorrow")).is_err());
+        assert!(parse(args("play --daily --adversarial")).is_err());
+    }
+
+    #[test]
+    fn bench_options() {
+        assert_eq!(
+            parse(args("bench")),
+            Ok(Command::Bench(bench::Options::default()))
+        );
+        assert_eq!(
+            parse(args("bench --algorithm prior --max-games 100 --record games.jsonl --output games.csv --verbose --unlimited")),
+            Ok(Command::Bench(bench::Options {
+                algorithm: "prior".to_string(),
+                max_games: Some(100),
+                answers_file: None,
+                filter: None,
+                record: Some("games.jsonl".to_string()),
+                output: Some("games.csv".to_string()),
+                verbose: true,
+                unlimited: true,
+                opener: Vec::new(),
+                matrix: None,
+            }))
+        );
+        assert_eq!(
+            parse(args("bench --opener Salet,courd")),
+            Ok(Command::Bench(bench::Options { opener: args("salet courd"), ..Default::default() }))
+        );
+        assert_eq!(
+            parse(args("bench --matrix patterns.bin")),
+            Ok(Command::Bench(bench::Options { matrix: Some("patterns.bin".to_string()), ..Default::default() }))
+        );
+        assert_eq!(
+            parse(args("bench --answers-file answers.txt --filter Ca")),
+            Ok(Command::Bench(bench::Options {
+                answers_file: Some("answers.txt".to_string()),
+                filter: Some("ca".to_string()),
+                ..Default::default()
+            }))
+        );
+        assert!(parse(args("bench --max-games lots")).is_err());
+        assert!(parse(args("compare --max-games lots")).is_err());
+        assert!(parse(args("evaluate salet --max-games -1")).is_err());
+        assert!(parse(args("bench --algorithm")).is_err());
+    }
+
+    #[test]
+    fn compare_options() {
+        assert_eq!(parse(args("compare")), Ok(Command::Compare(compare::Options::default())));
+        assert_eq!(
+            parse(args("compare --algorithms entropy,random --max-games 50 --csv out.csv --opener salet")),
+            Ok(Command::Compare(compare::Options {
+                algorithms: vec!["entropy".to_string(), "random".to_string()],
+                max_games: Some(50),
+                csv: Some("out.csv".to_string()),
+                opener: args("salet"),
+            }))
+        );
+        assert!(parse(args("compare --algorithms")).is_err());
+    }
+
+    #[test]
+    fn evaluate_options() {
+        assert_eq!(
+            parse(args("evaluate raise")),
+            Ok(Command::Evaluate(evaluate::Options { opener: "raise".to_string(), algorithm: "entropy".to_string(), max_games: None }))
+        );
+        assert_eq!(
+            parse(args("evaluate salet --algorithm frequency --max-games 10")),
+            Ok(Command::Evaluate(evaluate::Options { opener: "salet".to_string(), algorithm: "frequency".to_string(), max_games: Some(10) }))
+        );
+        assert!(parse(args("evaluate")).is_err());
+    }
+
+    #[test]
+    fn hint_history() {
+        assert_eq!(
+            parse(args("hint crane:GYBBY slate:BBBBG")),
+            Ok(Command::Hint { history: args("crane:GYBBY slate:BBBBG") })
+        );
+    }
+
+    #[test]
+    fn no_color() {
+        let mut a = args("--no-color play --share --no-color");
+        assert!(!take_color(&mut a));
+        assert_eq!(a, args("play --share"));
+    }
+
+    #[test]
+    fn dictionary() {
+        let path = std::env::temp_dir().join("wordle_cli_dictionary.txt");
+        std::fs::write(&path, "abcd\nefgh 3\n").unwrap();
+        let mut a = args(&format!("play --dictionary {} --share", path.display()));
+        let w = take_wordle(&mut a);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(w.unwrap().words(), ["abcd", "efgh"]);
+        assert_eq!(a, args("play --share"));
+        assert!(take_wordle(&mut args("play --dictionary")).is_err());
+        assert!(take_wordle(&mut args("play --dictionary /nonexistent/words.txt")).is_err());
+    }
+
+    #[test]
+    fn bench_answers_outside_the_answer_list() {
+        let w = Wordle::from_words(["cigar", "rebut", "sissy", "humph", "awake"]).unwrap()
+            .with_answers(["cigar", "rebut"]).unwrap();
+        let answers = std::env::temp_dir().join("wordle_cli_bench_answers.txt");
+        let output = std::env::temp_dir().join("wordle_cli_bench_answers.csv");
+        std::fs::write(&answers, "sissy\nhumph\nsissy\n").unwrap();
+        for algorithm in ["entropy", "frequency", "random"] {
+            let options = bench::Options {
+                algorithm: algorithm.to_string(),
+                answers_file: Some(answers.display().to_string()),
+                output: Some(output.display().to_string()),
+                ..Default::default()
+            };
+            let ran = bench::run(&w, &options, false);
+            let csv = std::fs::read_to_string(&output);
+            assert_eq!(ran, Ok(()), "{}", algorithm);
+            let csv = csv.unwrap();
+            assert_eq!(csv.lines().count(), 4, "{}", algorithm);
+            assert!(csv.lines().skip(1).all(|row| row.split(',').nth(3) == Some("true")), "{}: {}", algorithm, csv);
+        }
+        std::fs::remove_file(&answers).unwrap();
+        std::fs::remove_file(&output).unwrap();
+    }
+}
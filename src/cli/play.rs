@@ -0,0 +1,128 @@
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use wordle::daily::{Daily, Date};
+use wordle::hosts::{Adversarial, Honest};
+use wordle::{render, GameState, Guess, Guesser, Wordle};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Options {
+    /// Play against a host that avoids picking an answer for as long as it can.
+    pub adversarial: bool,
+    /// Print the emoji grid at the end.
+    pub share: bool,
+    /// Play the daily puzzle of this date instead of a random answer.
+    pub daily: Option<Date>,
+    /// Save the game to this file when quitting before the end, and resume the game
+    /// saved there if there is one.
+    pub save: Option<String>,
+}
+
+/// Plays one interactive game in the terminal.
+pub fn run(wordle: &Wordle, options: &Options, color: bool) -> Result<(), String> {
+    let saved = match &options.save {
+        Some(path) if Path::new(path).exists() => {
+            let state = GameState::load(path).map_err(|e| format!("cannot resume the game in {}: {}", path, e))?;
+            let (wordle, answer) = state.resume(wordle).map_err(|e| format!("cannot resume the game in {}: {}", path, e))?;
+            Some((wordle, answer, state.history))
+        }
+        _ => None,
+    };
+    let daily = Daily::new();
+    let puzzle = match options.daily {
+        Some(date) if saved.is_none() => Some(daily.number(date).ok_or_else(|| format!("there was no puzzle on {}", date))?),
+        _ => None,
+    };
+    let (wordle, answer, replay) = match saved {
+        Some((wordle, answer, history)) => (wordle, Some(answer), history),
+        None => {
+            let answer = (!options.adversarial).then(|| puzzle.map_or_else(|| random_answer(wordle), |n| daily.answer(wordle, n)).to_string());
+            (wordle.clone(), answer, Vec::new())
+        }
+    };
+    let config = wordle.config();
+    match puzzle {
+        Some(n) => println!("Wordle {}: guess the {}-letter word. You have {} tries.", n, config.word_len, config.max_guesses),
+        None => println!("Guess the {}-letter word. You have {} tries.", config.word_len, config.max_guesses),
+    }
+    for guess in &replay {
+        println!("{}", render::guess(guess, color));
+    }
+    let human = Human {
+        wordle: &wordle,
+        color,
+        replay: replay.iter().map(|guess| guess.word.to_string()).collect(),
+        save: options.save.as_deref().zip(answer.as_deref()),
+    };
+    let result = match &answer {
+        Some(answer) => wordle.play_against(Honest::new(&wordle, answer), human),
+        None => wordle.play_against(Adversarial::new(&wordle), human),
+    }.map_err(|e| e.to_string())?;
+    if let Some(path) = &options.save {
+        // the game is over, so there is nothing left to resume
+        let _ = fs::remove_file(path);
+    }
+    super::stats::record(&result);
+    if let Some(last) = result.history.last() {
+        println!("{}", render::guess(last, color));
+    }
+    match result.solved_in() {
+        Some(n) => println!("You got it in {}!", n),
+        None => println!("Out of guesses. The word was '{}'.", result.answer),
+    }
+    if options.share {
+        println!("\n{}", result.share_grid(puzzle, config.max_guesses));
+    }
+    Ok(())
+}
+
+pub fn random_answer(wordle: &Wordle) -> &'static str {
+    let answers = wordle.answers();
+    let seed = RandomState::new().build_hasher().finish();
+    answers[seed as usize % answers.len()]
+}
+
+struct Human<'a> {
+    wordle: &'a Wordle,
+    color: bool,
+    /// The guesses of a resumed game, played again before asking for more.
+    replay: Vec<String>,
+    /// Where to save the game on quitting, and its answer.
+    save: Option<(&'a str, &'a str)>,
+}
+
+impl Guesser for Human<'_> {
+    fn guess(&mut self, history: &[Guess]) -> String {
+        if let Some(word) = self.replay.get(history.len()) {
+            return word.clone();
+        }
+        if let Some(last) = history.last().filter(|_| history.len() > self.replay.len()) {
+            println!("{}", render::guess(last, self.color));
+        }
+        let stdin = io::stdin();
+        loop {
+            print!("guess {}> ", history.len() + 1);
+            io::stdout().flush().expect("stdout is writable");
+            let mut line = String::new();
+            if stdin.lock().read_line(&mut line).expect("stdin is readable") == 0 {
+                println!();
+                if let Some((path, answer)) = self.save {
+                    let history = history.iter().map(|guess| Guess { word: guess.word.to_string().into(), mask: guess.mask.clone() }).collect();
+                    match GameState::new(self.wordle, answer, history).with_hashed_answer().save(path) {
+                        Ok(()) => println!("Saved the game to {}.", path),
+                        Err(e) => eprintln!("error: cannot save the game to {}: {}", path, e),
+                    }
+                }
+                std::process::exit(0);
+            }
+            let word = line.trim().to_lowercase();
+            if self.wordle.contains(&word) {
+                return word;
+            }
+            println!("'{}' is not in the dictionary", word);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use wordle::{render, replay, Wordle};
+
+/// Checks every transcript in the file against the feedback rules and the dictionary, and
+/// shows each game.
+pub fn run(wordle: &Wordle, path: &str, color: bool) -> Result<(), String> {
+    let transcripts = replay::load(path).map_err(|e| format!("cannot read '{}': {}", path, e))?;
+    let mut inconsistent = 0;
+    for transcript in &transcripts {
+        let turns = match transcript.won {
+            true => transcript.guesses.len().to_string(),
+            false => "X".to_string(),
+        };
+        match transcript.elapsed {
+            Some(elapsed) => println!("{} {} ({:.2?})", transcript.answer, turns, elapsed),
+            None => println!("{} {}", transcript.answer, turns),
+        }
+        print!("{}", render::history(&transcript.guesses, color));
+        if let Err(e) = transcript.verify(wordle) {
+            println!("inconsistent: {}", e);
+            inconsistent += 1;
+        }
+        println!();
+    }
+    println!("{} games, {} inconsistent", transcripts.len(), inconsistent);
+    if inconsistent > 0 {
+        return Err(format!("{} of {} games are inconsistent", inconsistent, transcripts.len()));
+    }
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use wordle::stats::{self, PlayerStats};
+use wordle::{GameResult, Wordle};
+
+/// Adds an interactive game to the player's stats. Failing to do so is only worth a
+/// warning, since the game itself went fine.
+pub fn record(result: &GameResult) {
+    let Some(path) = stats::default_path() else {
+        return;
+    };
+    let saved = stats::load(&path).and_then(|mut player| {
+        player.record(result);
+        stats::save(&path, &player)
+    });
+    if let Err(e) = saved {
+        eprintln!("warning: cannot update stats in '{}': {}", path.display(), e);
+    }
+}
+
+/// Shows the player's stats, with a row for every allowed number of guesses.
+pub fn run(wordle: &Wordle) -> Result<(), String> {
+    let path = stats::default_path().ok_or("no config directory to keep stats in")?;
+    let mut player: PlayerStats = stats::load(&path).map_err(|e| format!("cannot read '{}': {}", path.display(), e))?;
+    let rows = player.distribution.len().max(wordle.config().max_guesses);
+    player.distribution.resize(rows, 0);
+    print!("{}", player);
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use std::io::{self, BufRead, Write};
+
+use wordle::hosts::Honest;
+use wordle::{render, Correctness, Guess, Guesser, LetterState, LetterStates, Wordle};
+
+use super::play::random_answer;
+
+const SUGGESTIONS: usize = 5;
+const KEYBOARD: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+/// Plays one game on a full-screen board, redrawn after every guess, with the keyboard
+/// below it and the solver's suggestions beside it.
+///
+/// Guesses are still typed as whole lines, since reading single keys needs raw terminal
+/// mode, which the standard library cannot switch to.
+pub fn run(wordle: &Wordle, color: bool) -> Result<(), String> {
+    let answer = random_answer(wordle);
+    let player = Player { wordle, color, message: String::new() };
+    let result = wordle.play_against(Honest::new(wordle, answer), player).map_err(|e| e.to_string())?;
+    super::stats::record(&result);
+    let message = match result.solved_in() {
+        Some(n) => format!("You got it in {}!", n),
+        None => format!("Out of guesses. The word was '{}'.", result.answer),
+    };
+    draw(wordle, &result.history, &[], &message, color);
+    Ok(())
+}
+
+struct Player<'a> {
+    wordle: &'a Wordle,
+    color: bool,
+    /// Shown under the board on the next redraw.
+    message: String,
+}
+
+impl Guesser for Player<'_> {
+    fn guess(&mut self, history: &[Guess]) -> String {
+        let hints: Vec<String> = self.wordle.hint(history, SUGGESTIONS).into_iter()
+            .map(|hint| format!("{} {:.2} bits", hint.word, hint.bits))
+            .collect();
+        let stdin = io::stdin();
+        loop {
+            draw(self.wordle, history, &hints, &std::mem::take(&mut self.message), self.color);
+            print!("> ");
+            io::stdout().flush().expect("stdout is writable");
+            let mut line = String::new();
+            if stdin.lock().read_line(&mut line).expect("stdin is readable") == 0 {
+                println!();
+                std::process::exit(0);
+            }
+            let word = line.trim().to_lowercase();
+            if self.wordle.contains(&word) {
+                return word;
+            }
+            self.message = format!("'{}' is not in the dictionary", word);
+        }
+    }
+}
+
+/// Clears the screen and draws the board, the suggestions, the keyboard and `message`.
+fn draw(wordle: &Wordle, history: &[Guess], hints: &[String], message: &str, color: bool) {
+    let config = wordle.config();
+    let width = match color {
+        true => 3 * config.word_len,
+        false => 2 * config.word_len + 1,
+    };
+    let mut panel = vec!["suggestions:".to_string()];
+    panel.extend(hints.iter().cloned());
+    let mut out = String::from("\x1b[2J\x1b[H");
+    for row in 0..config.max_guesses.max(panel.len()) {
+        let (cell, visible) = match history.get(row) {
+            Some(guess) => (render::guess(guess, color).to_string(), width),
+            None if row >= config.max_guesses => (String::new(), 0),
+            None if color => (" _ ".repeat(config.word_len), width),
+            None => ("_".repeat(config.word_len), config.word_len),
+        };
+        let side = panel.get(row).map_or("", String::as_str);
+        out.push_str(&format!("{}{}    {}\n", cell, " ".repeat(width - visible), side));
+    }
+    out.push('\n');
+    let states = LetterStates::from_history(history);
+    for (indent, keys) in KEYBOARD.iter().enumerate() {
+        out.push_str(&" ".repeat(indent * 2));
+        for letter in keys.chars() {
+            out.push_str(&key(letter, states.state(letter), color));
+        }
+        out.push('\n');
+    }
+    println!("{}\n{}", out, message);
+}
+
+/// One key of the keyboard, three columns wide.
+fn key(letter: char, state: LetterState, color: bool) -> String {
+    let letter = letter.to_ascii_uppercase();
+    let c = match state {
+        LetterState::Correct => Correctness::Correct,
+        LetterState::Present => Correctness::Misplaced,
+        LetterState::Absent => Correctness::Wrong,
+        LetterState::Unknown => return format!(" {} ", letter),
+    };
+    match (c, color) {
+        (c, true) => render::guess(&Guess { word: letter.to_string().into(), mask: vec![c] }, true).to_string(),
+        (Correctness::Correct, false) => format!("[{}]", letter),
+        (Correctness::Misplaced, false) => format!("({})", letter),
+        (Correctness::Wrong, false) => " . ".to_string(),
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use crate::Wordle;
+
+/// What is known about the answer, written as letters rather than as a history of
+/// guesses, for joining a game part of the way through.
+///
+/// A word matches when it has every green letter in place, contains every yellow letter
+/// (as many times as it is listed) outside the green positions, and has no gray letter
+/// beyond the greens and yellows of the same letter.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Constraints {
+	/// The letter known at each position, if any.
+	pub green: Vec<Option<char>>,
+	pub yellow: Vec<char>,
+	pub gray: Vec<char>,
+}
+
+impl Constraints {
+	/// Parses the constraints from a green pattern such as "_r_ne", with `_` or `.` for
+	/// unknown positions, and the yellow and gray letters. An empty pattern means no
+	/// greens; otherwise it must have `word_len` positions.
+	pub fn parse(green: &str, yellow: &str, gray: &str, word_len: usize) -> Result<Self, String> {
+		let green: Vec<Option<char>> = green.chars().map(|c| match c {
+			'_' | '.' => Ok(None),
+			c if c.is_alphabetic() => Ok(c.to_lowercase().next()),
+			c => Err(format!("'{}' is not a letter or '_' in the green pattern", c)),
+		}).collect::<Result<_, _>>()?;
+		if !green.is_empty() && green.len() != word_len {
+			return Err(format!("the green pattern has {} positions, not {}", green.len(), word_len));
+		}
+		let letters = |name: &str, s: &str| s.chars().map(|c| match c {
+			c if c.is_alphabetic() => Ok(c.to_lowercase().next().expect("letters have a lowercase form")),
+			c => Err(format!("'{}' is not a letter in the {} letters", c, name)),
+		}).collect::<Result<Vec<_>, _>>();
+		Ok(Self { green, yellow: letters("yellow", yellow)?, gray: letters("gray", gray)? })
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.green.iter().all(Option::is_none) && self.yellow.is_empty() && self.gray.is_empty()
+	}
+
+	/// Whether `word` could be the answer.
+	pub fn matches(&self, word: &str) -> bool {
+		let letters: Vec<char> = word.chars().collect();
+		if !self.green.is_empty() && letters.len() != self.green.len() {
+			return false;
+		}
+		let greens_kept = self.green.iter().zip(&letters).all(|(green, &letter)| green.is_none_or(|g| g == letter));
+		// the letters left over once the greens are accounted for
+		let mut rest: Vec<char> = letters.iter().enumerate()
+			.filter(|&(i, _)| self.green.get(i).is_none_or(Option::is_none))
+			.map(|(_, &letter)| letter)
+			.collect();
+		for yellow in &self.yellow {
+			match rest.iter().position(|letter| letter == yellow) {
+				Some(i) => {
+					rest.swap_remove(i);
+				}
+				None => return false,
+			}
+		}
+		greens_kept && rest.iter().all(|letter| !self.gray.contains(letter))
+	}
+
+	/// A copy of `wordle` whose only possible answers are those matching the constraints,
+	/// so that solvers and hints work from what is known so far.
+	pub fn narrow(&self, wordle: &Wordle) -> Wordle {
+		let mut narrowed = wordle.clone();
+		narrowed.answers = Arc::new(wordle.answers().iter().copied().filter(|word| self.matches(word)).collect());
+		narrowed
+	}
+}
@@ -0,0 +1,161 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::Wordle;
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Date {
+	year: i32,
+	month: u32,
+	day: u32,
+}
+
+impl Date {
+	/// The date, or `None` if there is no such day.
+	pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+		let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+		let days_in_month = match month {
+			1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+			4 | 6 | 9 | 11 => 30,
+			2 if leap => 29,
+			2 => 28,
+			_ => return None,
+		};
+		(1..=days_in_month).contains(&day).then_some(Self { year, month, day })
+	}
+
+	/// Today's date in UTC.
+	pub fn today() -> Self {
+		let secs = SystemTime::now().duration_since(UNIX_EPOCH).expect("clock is after 1970").as_secs();
+		Self::from_days((secs / 86400) as i64)
+	}
+
+	/// Days since 1970-01-01, which may be negative.
+	pub fn days(self) -> i64 {
+		// Howard Hinnant's days_from_civil
+		let y = self.year as i64 - (self.month <= 2) as i64;
+		let era = y.div_euclid(400);
+		let yoe = y - era * 400;
+		let m = self.month as i64;
+		let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + self.day as i64 - 1;
+		let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+		era * 146097 + doe - 719468
+	}
+
+	/// The date `days` days after 1970-01-01.
+	pub fn from_days(days: i64) -> Self {
+		// Howard Hinnant's civil_from_days
+		let z = days + 719468;
+		let era = z.div_euclid(146097);
+		let doe = z - era * 146097;
+		let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+		let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+		let mp = (5 * doy + 2) / 153;
+		let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+		let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+		let year = (yoe + era * 400 + (month <= 2) as i64) as i32;
+		Self { year, month, day }
+	}
+}
+
+impl fmt::Display for Date {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+	}
+}
+
+impl FromStr for Date {
+	type Err = String;
+
+	/// Parses a date written as YYYY-MM-DD.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let invalid = || format!("'{}' is not a date like 2022-01-31", s);
+		let mut parts = s.splitn(3, '-');
+		let mut next = || parts.next().and_then(|part| part.parse().ok()).ok_or_else(invalid);
+		let (year, month, day) = (next()?, next()? as u32, next()? as u32);
+		Date::new(year, month, day).ok_or_else(invalid)
+	}
+}
+
+/// Maps calendar dates to puzzles, so that everyone gets the same answer on the same day.
+///
+/// Puzzle `n` is the one `n` days after the epoch, and its answer is answer `n` of the
+/// answer list, wrapping around once the list runs out. With the default epoch and the
+/// embedded answer list this follows the original game's numbering.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Daily {
+	epoch: Date,
+}
+
+impl Daily {
+	/// The original game's epoch: puzzle 0 was on 2021-06-19.
+	pub fn new() -> Self {
+		Self { epoch: Date { year: 2021, month: 6, day: 19 } }
+	}
+
+	pub fn with_epoch(epoch: Date) -> Self {
+		Self { epoch }
+	}
+
+	/// The puzzle number for `date`, or `None` if it is before the epoch.
+	pub fn number(&self, date: Date) -> Option<usize> {
+		usize::try_from(date.days() - self.epoch.days()).ok()
+	}
+
+	/// The answer of puzzle `number`.
+	pub fn answer(&self, wordle: &Wordle, number: usize) -> &'static str {
+		let answers = wordle.answers();
+		answers[number % answers.len()]
+	}
+}
+
+impl Default for Daily {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::daily::{Daily, Date};
+
+	#[test]
+	fn days_round_trip() {
+		for days in [-800_000, -1, 0, 1, 18_797, 19_000, 2_000_000] {
+			assert_eq!(Date::from_days(days).days(), days);
+		}
+		assert_eq!(Date::from_days(0), Date::new(1970, 1, 1).unwrap());
+		assert_eq!(Date::new(2024, 2, 29).unwrap().days() + 1, Date::new(2024, 3, 1).unwrap().days());
+	}
+
+	#[test]
+	fn parse() {
+		assert_eq!("2022-01-01".parse(), Ok(Date::new(2022, 1, 1).unwrap()));
+		assert_eq!(Date::new(2022, 1, 1).unwrap().to_string(), "2022-01-01");
+		assert!("2023-02-29".parse::<Date>().is_err());
+		assert!("2023-13-01".parse::<Date>().is_err());
+		assert!("yesterday".parse::<Date>().is_err());
+	}
+
+	#[test]
+	#[cfg(feature = "full-dictionary")]
+	fn official_numbering() {
+		let w = crate::Wordle::new();
+		let daily = Daily::new();
+		let epoch = Date::new(2021, 6, 19).unwrap();
+		assert_eq!(daily.number(epoch), Some(0));
+		assert_eq!(daily.answer(&w, 0), "cigar");
+		// New Year's Day 2022 was Wordle 196
+		assert_eq!(daily.number(Date::new(2022, 1, 1).unwrap()), Some(196));
+		assert_eq!(daily.number(Date::new(2021, 6, 18).unwrap()), None);
+		assert_eq!(daily.answer(&w, w.answers().len()), "cigar");
+	}
+
+	#[test]
+	fn custom_epoch() {
+		let daily = Daily::with_epoch(Date::new(2024, 1, 1).unwrap());
+		assert_eq!(daily.number(Date::new(2024, 12, 31).unwrap()), Some(365));
+	}
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Counts the words of a text corpus or a frequency list, to build a dictionary in the
+/// format read by [`crate::Wordle::from_dictionary`].
+///
+/// Words are [normalized](normalize) first, and only those of the chosen length are
+/// kept.
+#[derive(Debug, Clone)]
+pub struct WordCounter {
+	word_len: usize,
+	keep_accents: bool,
+	counts: HashMap<String, usize>,
+}
+
+impl WordCounter {
+	pub fn new(word_len: usize) -> Self {
+		Self { word_len, keep_accents: false, counts: HashMap::new() }
+	}
+
+	/// Keeps accented letters instead of replacing them with their base letter.
+	pub fn with_accents(mut self, keep_accents: bool) -> Self {
+		self.keep_accents = keep_accents;
+		self
+	}
+
+	/// Counts every word of running text. Anything but letters separates words, so a
+	/// hyphenated or abbreviated word is split and its parts counted.
+	pub fn add_text(&mut self, text: &str) {
+		for word in text.split(|c: char| !c.is_alphabetic()) {
+			self.add(word, 1);
+		}
+	}
+
+	/// Reads a frequency list with a word and its count on each line, separated by
+	/// whitespace. Anything after the count is ignored, and counts for the same word add
+	/// up. Returns the line numbers (1-based) that had no count.
+	pub fn add_frequencies(&mut self, text: &str) -> Vec<usize> {
+		let mut invalid = Vec::new();
+		for (i, line) in text.lines().enumerate() {
+			let mut fields = line.split_whitespace();
+			match (fields.next(), fields.next().map(str::parse)) {
+				(None, _) => {}
+				(Some(word), Some(Ok(count))) => self.add(word, count),
+				_ => invalid.push(i + 1),
+			}
+		}
+		invalid
+	}
+
+	/// Adds `count` occurrences of `word`, unless it is not a word of the right length.
+	pub fn add(&mut self, word: &str, count: usize) {
+		let Some(word) = normalize(word, self.keep_accents) else {
+			return;
+		};
+		if word.chars().count() == self.word_len {
+			*self.counts.entry(word).or_default() += count;
+		}
+	}
+
+	pub fn len(&self) -> usize {
+		self.counts.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.counts.is_empty()
+	}
+
+	/// Writes the words seen at least `min_count` times in alphabetical order, each
+	/// followed by a space and its count.
+	pub fn write(&self, min_count: usize) -> String {
+		let mut words: Vec<_> = self.counts.iter().filter(|&(_, &count)| count >= min_count).collect();
+		words.sort_unstable();
+		let mut out = String::new();
+		for (word, count) in words {
+			writeln!(out, "{} {}", word, count).unwrap();
+		}
+		out
+	}
+}
+
+/// Lowercases `word` and, unless `keep_accents`, replaces accented Latin letters with
+/// their base letter. `None` if the word has anything but letters with a lowercase form,
+/// which dictionaries cannot hold.
+pub fn normalize(word: &str, keep_accents: bool) -> Option<String> {
+	let mut normalized = String::new();
+	for c in word.chars().flat_map(char::to_lowercase) {
+		if !c.is_lowercase() {
+			return None;
+		}
+		normalized.push(if keep_accents { c } else { strip_accent(c) });
+	}
+	(!normalized.is_empty()).then_some(normalized)
+}
+
+/// The base letter of a lowercase accented Latin letter, or the letter itself.
+fn strip_accent(c: char) -> char {
+	match c {
+		'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => 'a',
+		'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => 'c',
+		'ď' | 'đ' => 'd',
+		'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => 'e',
+		'ĝ' | 'ğ' | 'ġ' | 'ģ' => 'g',
+		'ĥ' | 'ħ' => 'h',
+		'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => 'i',
+		'ĵ' => 'j',
+		'ķ' => 'k',
+		'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => 'l',
+		'ñ' | 'ń' | 'ņ' | 'ň' => 'n',
+		'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => 'o',
+		'ŕ' | 'ŗ' | 'ř' => 'r',
+		'ś' | 'ŝ' | 'ş' | 'š' => 's',
+		'ţ' | 'ť' | 'ŧ' => 't',
+		'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => 'u',
+		'ŵ' => 'w',
+		'ý' | 'ÿ' | 'ŷ' => 'y',
+		'ź' | 'ż' | 'ž' => 'z',
+		c => c,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::dictgen::{normalize, WordCounter};
+	use crate::Wordle;
+
+	#[test]
+	fn normalizes() {
+		assert_eq!(normalize("Crâne", false), Some("crane".to_string()));
+		assert_eq!(normalize("Crâne", true), Some("crâne".to_string()));
+		assert_eq!(normalize("ÉCOLE", false), Some("ecole".to_string()));
+		assert_eq!(normalize("don't", false), None);
+		assert_eq!(normalize("", false), None);
+	}
+
+	#[test]
+	fn builds_a_dictionary() {
+		let mut counter = WordCounter::new(5);
+		counter.add_text("The crane, the CRANE and the crâne-shaped hello; hello!");
+		assert_eq!(counter.add_frequencies("hello 3 1999\n\nslate\nirate 2\n"), [3]);
+		assert_eq!(counter.write(1), "crane 3\nhello 5\nirate 2\n");
+		assert_eq!(counter.write(3), "crane 3\nhello 5\n");
+		let path = std::env::temp_dir().join("wordle_dictgen_builds_a_dictionary.txt");
+		std::fs::write(&path, counter.write(1)).unwrap();
+		let w = Wordle::from_dictionary(&path).unwrap();
+		std::fs::remove_file(&path).unwrap();
+		assert_eq!(w.frequency("hello"), Some(5));
+		assert_eq!(w.words().len(), 3);
+	}
+}
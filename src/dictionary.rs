@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::io;
+
+/// The longest word length supported, limited by how many letters a `PackedCorrectness`
+/// can hold.
+pub(crate) const MAX_WORD_LEN: usize = 10;
+
+/// Why a word list could not be loaded.
+#[derive(Debug)]
+pub enum DictionaryError {
+	Io(io::Error),
+	/// The word on this (1-based) line is not lowercase letters, is too long, or
+	/// differs in length from the first word.
+	InvalidWord { line: usize, word: String },
+	/// The frequency on this (1-based) line is not a number.
+	InvalidFrequency { line: usize, frequency: String },
+	Empty,
+}
+
+impl fmt::Display for DictionaryError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			DictionaryError::Io(e) => write!(f, "failed to read dictionary: {}", e),
+			DictionaryError::InvalidWord { line, word } => write!(f, "line {}: '{}' is not a valid word of the dictionary's length", line, word),
+			DictionaryError::InvalidFrequency { line, frequency } => write!(f, "line {}: '{}' is not a frequency", line, frequency),
+			DictionaryError::Empty => write!(f, "dictionary has no words"),
+		}
+	}
+}
+
+impl std::error::Error for DictionaryError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			DictionaryError::Io(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl From<io::Error> for DictionaryError {
+	fn from(e: io::Error) -> Self {
+		DictionaryError::Io(e)
+	}
+}
+
+/// Parses one word per line, optionally followed by a space and its frequency. Words
+/// without a frequency count as 1. Blank lines are skipped. Every word must have the
+/// length of the first one, which is returned alongside the words.
+pub(crate) fn parse(text: &'static str) -> Result<(HashMap<&'static str, usize>, usize), DictionaryError> {
+	let mut dictionary = HashMap::new();
+	let mut word_len = None;
+	for (i, line) in text.lines().enumerate() {
+		let line = line.trim();
+		if line.is_empty() {
+			continue;
+		}
+		let (word, count) = match line.split_once(' ') {
+			Some((word, count)) => {
+				let count = count.trim().parse().map_err(|_| DictionaryError::InvalidFrequency {
+					line: i + 1,
+					frequency: count.to_string(),
+				})?;
+				(word, count)
+			}
+			None => (line, 1),
+		};
+		if !is_valid_word(word, *word_len.get_or_insert(word.chars().count())) {
+			return Err(DictionaryError::InvalidWord { line: i + 1, word: word.to_string() });
+		}
+		dictionary.insert(word, count);
+	}
+	match word_len {
+		Some(word_len) => Ok((dictionary, word_len)),
+		None => Err(DictionaryError::Empty),
+	}
+}
+
+/// Validates `words` and keeps them alive for the rest of the program. Every word must
+/// have length `word_len`, or the length of the first word if that is `None`.
+pub(crate) fn leak_words<I, S>(words: I, mut word_len: Option<usize>) -> Result<Vec<&'static str>, DictionaryError>
+where
+	I: IntoIterator<Item = S>,
+	S: AsRef<str>,
+{
+	let mut leaked = Vec::new();
+	for (i, word) in words.into_iter().enumerate() {
+		let word = word.as_ref();
+		if !is_valid_word(word, *word_len.get_or_insert(word.chars().count())) {
+			return Err(DictionaryError::InvalidWord { line: i + 1, word: word.to_string() });
+		}
+		leaked.push(&*Box::leak(word.into()));
+	}
+	if leaked.is_empty() {
+		return Err(DictionaryError::Empty);
+	}
+	Ok(leaked)
+}
+
+/// Whether `word` is `word_len` lowercase letters, which need not be ASCII.
+fn is_valid_word(word: &str, word_len: usize) -> bool {
+	(1..=MAX_WORD_LEN).contains(&word_len) && word.chars().count() == word_len && word.chars().all(char::is_lowercase)
+}
@@ -0,0 +1,85 @@
+use std::fmt;
+
+use crate::mask::{self, Style};
+use crate::{Correctness, DictionaryError};
+
+/// Why a game could not be played to the end.
+#[derive(Debug)]
+pub enum WordleError {
+	Dictionary(DictionaryError),
+	/// The guesser guessed a word that is not in the dictionary.
+	NotInDictionary { guess: String },
+	/// The guesser ignored a revealed hint in hard mode.
+	IgnoresHints { guess: String },
+	/// The guesser guessed a word of the wrong length.
+	WrongLength { guess: String, expected: usize },
+	/// The game was given an answer that is not in the answer list.
+	UnknownAnswer { answer: String },
+}
+
+impl fmt::Display for WordleError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			WordleError::Dictionary(e) => write!(f, "{}", e),
+			WordleError::NotInDictionary { guess } => write!(f, "guess '{}' is not in the dictionary", guess),
+			WordleError::IgnoresHints { guess } => write!(f, "guess '{}' ignores hints in hard mode", guess),
+			WordleError::WrongLength { guess, expected } => write!(f, "guess '{}' does not have {} letters", guess, expected),
+			WordleError::UnknownAnswer { answer } => write!(f, "answer '{}' is not in the answer list", answer),
+		}
+	}
+}
+
+impl std::error::Error for WordleError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			WordleError::Dictionary(e) => Some(e),
+			_ => None,
+		}
+	}
+}
+
+impl From<DictionaryError> for WordleError {
+	fn from(e: DictionaryError) -> Self {
+		WordleError::Dictionary(e)
+	}
+}
+
+/// Why a sequence of guesses could not have come from a real game, as found by
+/// [`crate::Wordle::verify`]. Turns count from 1.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VerifyError {
+	/// A guess has the wrong number of letters.
+	WrongLength { turn: usize, guess: String, expected: usize },
+	/// A guess got different feedback than the answer gives.
+	WrongMask { turn: usize, guess: String, mask: Vec<Correctness>, expected: Vec<Correctness> },
+	/// A guess other than the answer is not in the dictionary.
+	NotInDictionary { turn: usize, guess: String },
+	/// A guess ignores a revealed hint in hard mode.
+	IgnoresHints { turn: usize, guess: String },
+	/// The game goes on after the answer was found.
+	PlayedAfterWin { turn: usize },
+	/// There are more guesses than the game allows.
+	TooManyGuesses { guesses: usize, max_guesses: usize },
+}
+
+impl fmt::Display for VerifyError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			VerifyError::WrongLength { turn, guess, expected } => {
+				write!(f, "guess {} '{}' does not have {} letters", turn, guess, expected)
+			}
+			VerifyError::WrongMask { turn, guess, mask, expected } => write!(
+				f, "guess {} '{}' is marked {} but should be {}",
+				turn, guess, mask::display(mask, Style::Compact), mask::display(expected, Style::Compact)
+			),
+			VerifyError::NotInDictionary { turn, guess } => write!(f, "guess {} '{}' is not in the dictionary", turn, guess),
+			VerifyError::IgnoresHints { turn, guess } => write!(f, "guess {} '{}' ignores hints in hard mode", turn, guess),
+			VerifyError::PlayedAfterWin { turn } => write!(f, "game goes on after guess {} found the answer", turn),
+			VerifyError::TooManyGuesses { guesses, max_guesses } => {
+				write!(f, "{} guesses were made but only {} are allowed", guesses, max_guesses)
+			}
+		}
+	}
+}
+
+impl std::error::Error for VerifyError {}
@@ -0,0 +1,238 @@
+//! A C interface to the engine, for embedding the solver in programs not written in
+//! Rust. Enabled by the `ffi` feature; `include/wordle.h` declares it. Build a library
+//! to link against with
+//!
+//! ```text
+//! cargo rustc --lib --release --features ffi --crate-type staticlib
+//! ```
+//!
+//! (or `cdylib` for a shared library).
+//!
+//! Strings are nul-terminated UTF-8. Functions that return text write it to a
+//! caller-provided buffer the way `snprintf` does: they return the length of the whole
+//! text, and write as much of it as fits, always followed by a nul, so a return value
+//! of at least the buffer size means the text was cut short.
+
+use std::borrow::Cow;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use std::slice;
+
+use crate::{Correctness, Guess, Wordle};
+
+pub const WORDLE_WRONG: u8 = 0;
+pub const WORDLE_MISPLACED: u8 = 1;
+pub const WORDLE_CORRECT: u8 = 2;
+
+/// The guesses of one game so far, with their feedback.
+pub struct WordleHistory(Vec<Guess<'static>>);
+
+/// Builds an engine over the embedded dictionary, or returns null if the library was
+/// built without one. Free it with [`wordle_free`].
+#[no_mangle]
+pub extern "C" fn wordle_new() -> *mut Wordle {
+	#[cfg(any(feature = "full-dictionary", feature = "answers-dictionary"))]
+	return Box::into_raw(Box::new(Wordle::new()));
+	#[cfg(not(any(feature = "full-dictionary", feature = "answers-dictionary")))]
+	return ptr::null_mut();
+}
+
+/// Builds an engine over a dictionary file, as [`Wordle::from_dictionary`] does, or
+/// returns null if it cannot be loaded.
+///
+/// # Safety
+///
+/// `path` must be a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_from_dictionary(path: *const c_char) -> *mut Wordle {
+	let Some(path) = str_arg(path) else {
+		return ptr::null_mut();
+	};
+	match Wordle::from_dictionary(path) {
+		Ok(wordle) => Box::into_raw(Box::new(wordle)),
+		Err(_) => ptr::null_mut(),
+	}
+}
+
+/// # Safety
+///
+/// `wordle` must come from [`wordle_new`] or [`wordle_from_dictionary`] and not have
+/// been freed, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_free(wordle: *mut Wordle) {
+	if !wordle.is_null() {
+		drop(Box::from_raw(wordle));
+	}
+}
+
+/// Writes the feedback for `guess` against `answer` to `mask`, one `WORDLE_*` value per
+/// letter, and returns the number of letters. Returns -1 if the words are not as long as
+/// the engine's words or `mask_len` is less than their length.
+///
+/// # Safety
+///
+/// `wordle` must be a live engine, the words nul-terminated strings and `mask` valid for
+/// writing `mask_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_compute_mask(wordle: *const Wordle, answer: *const c_char, guess: *const c_char, mask: *mut u8, mask_len: usize) -> c_int {
+	let (Some(wordle), Some(answer), Some(guess)) = (wordle.as_ref(), str_arg(answer), str_arg(guess)) else {
+		return -1;
+	};
+	let len = guess.chars().count();
+	if len != wordle.config().word_len || answer.chars().count() != len || len > mask_len || mask.is_null() {
+		return -1;
+	}
+	let computed = Correctness::compute_with(answer, guess, wordle.duplicate_rule());
+	for (i, c) in computed.into_iter().enumerate() {
+		*mask.add(i) = match c {
+			Correctness::Wrong => WORDLE_WRONG,
+			Correctness::Misplaced => WORDLE_MISPLACED,
+			Correctness::Correct => WORDLE_CORRECT,
+		};
+	}
+	len as c_int
+}
+
+/// Starts an empty history. Free it with [`wordle_history_free`].
+#[no_mangle]
+pub extern "C" fn wordle_history_new() -> *mut WordleHistory {
+	Box::into_raw(Box::new(WordleHistory(Vec::new())))
+}
+
+/// Adds a guess and its feedback, one `WORDLE_*` value per letter, to a history of a
+/// game played by `wordle`. Returns 0, or -1 if the guess is not as long as the engine's
+/// words or the mask does not have a valid value for every letter of it.
+///
+/// # Safety
+///
+/// `wordle` must be a live engine, `history` a live history, `guess` a nul-terminated
+/// string and `mask` valid for reading `mask_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_history_push(wordle: *const Wordle, history: *mut WordleHistory, guess: *const c_char, mask: *const u8, mask_len: usize) -> c_int {
+	let (Some(wordle), Some(history), Some(guess)) = (wordle.as_ref(), history.as_mut(), str_arg(guess)) else {
+		return -1;
+	};
+	let len = guess.chars().count();
+	if mask.is_null() || len != wordle.config().word_len || len != mask_len {
+		return -1;
+	}
+	let mask: Option<Vec<Correctness>> = slice::from_raw_parts(mask, mask_len).iter().map(|&c| match c {
+		WORDLE_WRONG => Some(Correctness::Wrong),
+		WORDLE_MISPLACED => Some(Correctness::Misplaced),
+		WORDLE_CORRECT => Some(Correctness::Correct),
+		_ => None,
+	}).collect();
+	match mask {
+		Some(mask) => {
+			history.0.push(Guess { word: Cow::Owned(guess.to_string()), mask });
+			0
+		}
+		None => -1,
+	}
+}
+
+/// # Safety
+///
+/// `history` must come from [`wordle_history_new`] and not have been freed, or be null.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_history_free(history: *mut WordleHistory) {
+	if !history.is_null() {
+		drop(Box::from_raw(history));
+	}
+}
+
+/// Writes the possible answers consistent with `history`, separated by spaces, to
+/// `out`. Returns the length of the text, or -1 if an argument is null.
+///
+/// # Safety
+///
+/// `wordle` and `history` must be live, and `out` valid for writing `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_candidates(wordle: *const Wordle, history: *const WordleHistory, out: *mut c_char, out_len: usize) -> isize {
+	let (Some(wordle), Some(history)) = (wordle.as_ref(), history.as_ref()) else {
+		return -1;
+	};
+	let candidates: Vec<&str> = wordle.candidates(&history.0).collect();
+	write_out(&candidates.join(" "), out, out_len)
+}
+
+/// Writes the best next guess after `history` to `out`, as [`Wordle::hint`] picks it.
+/// Returns the length of the guess, or -1 if an argument is null.
+///
+/// # Safety
+///
+/// `wordle` and `history` must be live, and `out` valid for writing `out_len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn wordle_hint(wordle: *const Wordle, history: *const WordleHistory, out: *mut c_char, out_len: usize) -> isize {
+	let (Some(wordle), Some(history)) = (wordle.as_ref(), history.as_ref()) else {
+		return -1;
+	};
+	let hint = wordle.hint(&history.0, 1).into_iter().next().map(|hint| hint.word).unwrap_or_default();
+	write_out(&hint, out, out_len)
+}
+
+/// Reads a nul-terminated UTF-8 argument, or `None` if it is null or not UTF-8.
+unsafe fn str_arg<'a>(s: *const c_char) -> Option<&'a str> {
+	if s.is_null() {
+		return None;
+	}
+	CStr::from_ptr(s).to_str().ok()
+}
+
+/// Writes as much of `text` as fits in `out`, followed by a nul, and returns its full
+/// length.
+unsafe fn write_out(text: &str, out: *mut c_char, out_len: usize) -> isize {
+	if !out.is_null() && out_len > 0 {
+		let text = CString::new(text).expect("words have no nul characters");
+		let bytes = text.as_bytes();
+		let n = bytes.len().min(out_len - 1);
+		ptr::copy_nonoverlapping(bytes.as_ptr(), out.cast::<u8>(), n);
+		*out.add(n) = 0;
+	}
+	text.len() as isize
+}
+
+#[cfg(all(test, feature = "full-dictionary"))]
+mod tests {
+	use std::ffi::{c_char, CStr};
+	use crate::ffi::*;
+
+	fn text(buffer: &[c_char]) -> &str {
+		unsafe { CStr::from_ptr(buffer.as_ptr()) }.to_str().unwrap()
+	}
+
+	#[test]
+	fn solves_through_c_calls() {
+		unsafe {
+			let wordle = wordle_new();
+			let mut mask = [0u8; 5];
+			assert_eq!(wordle_compute_mask(wordle, c"cigar".as_ptr(), c"crane".as_ptr(), mask.as_mut_ptr(), 5), 5);
+			assert_eq!(mask, [WORDLE_CORRECT, WORDLE_MISPLACED, WORDLE_MISPLACED, WORDLE_WRONG, WORDLE_WRONG]);
+			assert_eq!(wordle_compute_mask(wordle, c"cigar".as_ptr(), c"cranes".as_ptr(), mask.as_mut_ptr(), 5), -1);
+			// words of the same length, but not the engine's, get no feedback
+			let mut long = [0u8; 12];
+			assert_eq!(wordle_compute_mask(wordle, c"abcdefghijkl".as_ptr(), c"abcdefghijkl".as_ptr(), long.as_mut_ptr(), 12), -1);
+			assert_eq!(wordle_compute_mask(wordle, c"cigars".as_ptr(), c"cranes".as_ptr(), long.as_mut_ptr(), 12), -1);
+			let history = wordle_history_new();
+			assert_eq!(wordle_history_push(wordle, history, c"crane".as_ptr(), mask.as_ptr(), 5), 0);
+			assert_eq!(wordle_history_push(wordle, history, c"crane".as_ptr(), [9u8; 5].as_ptr(), 5), -1);
+			assert_eq!(wordle_history_push(wordle, history, c"cranes".as_ptr(), [WORDLE_WRONG; 6].as_ptr(), 6), -1);
+			let mut mask = [0u8; 5];
+			wordle_compute_mask(wordle, c"cigar".as_ptr(), c"sloth".as_ptr(), mask.as_mut_ptr(), 5);
+			wordle_history_push(wordle, history, c"sloth".as_ptr(), mask.as_ptr(), 5);
+			let mut out = [0 as c_char; 64];
+			let len = wordle_candidates(wordle, history, out.as_mut_ptr(), out.len());
+			assert!(text(&out).split(' ').any(|word| word == "cigar"));
+			assert_eq!(len as usize, text(&out).len());
+			// a short buffer gets as much as fits
+			let mut short = [0 as c_char; 3];
+			assert_eq!(wordle_candidates(wordle, history, short.as_mut_ptr(), short.len()), len);
+			assert_eq!(text(&short), &text(&out)[..2]);
+			assert_eq!(wordle_hint(wordle, history, out.as_mut_ptr(), out.len()), 5);
+			assert!((*wordle).contains(text(&out)));
+			wordle_history_free(history);
+			wordle_free(wordle);
+			assert!(wordle_from_dictionary(c"/no/such/dictionary".as_ptr()).is_null());
+		}
+	}
+}
@@ -0,0 +1,68 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use crate::{Correctness, DuplicateRule, Host, PackedCorrectness, Wordle};
+
+/// Gives the true feedback for a fixed answer, as in the official game.
+pub struct Honest<'a> {
+	answer: &'a str,
+	rule: DuplicateRule,
+}
+
+impl<'a> Honest<'a> {
+	pub fn new(wordle: &Wordle, answer: &'a str) -> Self {
+		Self { answer, rule: wordle.duplicate_rule }
+	}
+}
+
+impl Host for Honest<'_> {
+	fn respond(&mut self, guess: &str) -> Vec<Correctness> {
+		Correctness::compute_with(self.answer, guess, self.rule)
+	}
+
+	fn answer(&self) -> &str {
+		self.answer
+	}
+}
+
+/// Avoids committing to an answer, like Absurdle.
+///
+/// After each guess the remaining answers are grouped by the feedback they would give,
+/// and the host picks the feedback of the largest group. Ties go to the feedback that
+/// reveals the least, so the guess only wins once it is the last answer left.
+pub struct Adversarial {
+	remaining: Vec<&'static str>,
+	rule: DuplicateRule,
+}
+
+impl Adversarial {
+	pub fn new(wordle: &Wordle) -> Self {
+		Self {
+			remaining: wordle.answers().to_vec(),
+			rule: wordle.duplicate_rule,
+		}
+	}
+
+	/// The answers still consistent with every feedback given so far.
+	pub fn remaining(&self) -> &[&'static str] {
+		&self.remaining
+	}
+}
+
+impl Host for Adversarial {
+	fn respond(&mut self, guess: &str) -> Vec<Correctness> {
+		let mut buckets: HashMap<PackedCorrectness, Vec<&'static str>> = HashMap::new();
+		for &answer in &self.remaining {
+			buckets.entry(PackedCorrectness::compute(answer, guess, self.rule)).or_default().push(answer);
+		}
+		let (pattern, remaining) = buckets.into_iter()
+			.max_by_key(|(pattern, bucket)| (bucket.len(), Reverse(*pattern)))
+			.expect("there is always a remaining answer");
+		self.remaining = remaining;
+		pattern.to_mask(guess.chars().count())
+	}
+
+	fn answer(&self) -> &str {
+		self.remaining[0]
+	}
+}
@@ -0,0 +1,244 @@
+use std::fmt::Write;
+
+/// Just enough JSON to write and read game transcripts, without pulling in a
+/// serialization framework.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+	Null,
+	Bool(bool),
+	Number(f64),
+	String(String),
+	Array(Vec<Value>),
+	Object(Vec<(String, Value)>),
+}
+
+impl Value {
+	pub(crate) fn get(&self, key: &str) -> Option<&Value> {
+		match self {
+			Value::Object(fields) => fields.iter().find(|(k, _)| k == key).map(|(_, v)| v),
+			_ => None,
+		}
+	}
+
+	pub(crate) fn as_str(&self) -> Option<&str> {
+		match self {
+			Value::String(s) => Some(s),
+			_ => None,
+		}
+	}
+
+	pub(crate) fn as_bool(&self) -> Option<bool> {
+		match self {
+			Value::Bool(b) => Some(*b),
+			_ => None,
+		}
+	}
+
+	pub(crate) fn as_f64(&self) -> Option<f64> {
+		match self {
+			Value::Number(n) => Some(*n),
+			_ => None,
+		}
+	}
+
+	pub(crate) fn as_array(&self) -> Option<&[Value]> {
+		match self {
+			Value::Array(items) => Some(items),
+			_ => None,
+		}
+	}
+
+	/// Writes the value on a single line.
+	pub(crate) fn write(&self, out: &mut String) {
+		match self {
+			Value::Null => out.push_str("null"),
+			Value::Bool(b) => write!(out, "{}", b).unwrap(),
+			Value::Number(n) => write!(out, "{}", n).unwrap(),
+			Value::String(s) => write_string(s, out),
+			Value::Array(items) => {
+				out.push('[');
+				for (i, item) in items.iter().enumerate() {
+					if i > 0 {
+						out.push(',');
+					}
+					item.write(out);
+				}
+				out.push(']');
+			}
+			Value::Object(fields) => {
+				out.push('{');
+				for (i, (key, value)) in fields.iter().enumerate() {
+					if i > 0 {
+						out.push(',');
+					}
+					write_string(key, out);
+					out.push(':');
+					value.write(out);
+				}
+				out.push('}');
+			}
+		}
+	}
+}
+
+fn write_string(s: &str, out: &mut String) {
+	out.push('"');
+	for c in s.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+}
+
+/// Parses a single JSON value, which must make up all of `text` apart from whitespace.
+pub(crate) fn parse(text: &str) -> Result<Value, String> {
+	let mut parser = Parser { text, pos: 0 };
+	let value = parser.value()?;
+	parser.skip_whitespace();
+	if parser.pos != text.len() {
+		return Err(parser.error("trailing characters"));
+	}
+	Ok(value)
+}
+
+struct Parser<'a> {
+	text: &'a str,
+	pos: usize,
+}
+
+impl Parser<'_> {
+	fn error(&self, message: &str) -> String {
+		format!("{} at offset {}", message, self.pos)
+	}
+
+	fn peek(&self) -> Option<char> {
+		self.text[self.pos..].chars().next()
+	}
+
+	fn skip_whitespace(&mut self) {
+		while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+			self.pos += c.len_utf8();
+		}
+	}
+
+	fn expect(&mut self, expected: char) -> Result<(), String> {
+		self.skip_whitespace();
+		if self.peek() != Some(expected) {
+			return Err(self.error(&format!("expected '{}'", expected)));
+		}
+		self.pos += 1;
+		Ok(())
+	}
+
+	fn keyword(&mut self, word: &str, value: Value) -> Result<Value, String> {
+		if !self.text[self.pos..].starts_with(word) {
+			return Err(self.error("unexpected token"));
+		}
+		self.pos += word.len();
+		Ok(value)
+	}
+
+	fn value(&mut self) -> Result<Value, String> {
+		self.skip_whitespace();
+		match self.peek() {
+			Some('n') => self.keyword("null", Value::Null),
+			Some('t') => self.keyword("true", Value::Bool(true)),
+			Some('f') => self.keyword("false", Value::Bool(false)),
+			Some('"') => self.string().map(Value::String),
+			Some('[') => {
+				self.pos += 1;
+				let mut items = Vec::new();
+				self.skip_whitespace();
+				if self.peek() == Some(']') {
+					self.pos += 1;
+					return Ok(Value::Array(items));
+				}
+				loop {
+					items.push(self.value()?);
+					self.skip_whitespace();
+					match self.peek() {
+						Some(',') => self.pos += 1,
+						Some(']') => {
+							self.pos += 1;
+							return Ok(Value::Array(items));
+						}
+						_ => return Err(self.error("expected ',' or ']'")),
+					}
+				}
+			}
+			Some('{') => {
+				self.pos += 1;
+				let mut fields = Vec::new();
+				self.skip_whitespace();
+				if self.peek() == Some('}') {
+					self.pos += 1;
+					return Ok(Value::Object(fields));
+				}
+				loop {
+					self.skip_whitespace();
+					let key = self.string()?;
+					self.expect(':')?;
+					fields.push((key, self.value()?));
+					self.skip_whitespace();
+					match self.peek() {
+						Some(',') => self.pos += 1,
+						Some('}') => {
+							self.pos += 1;
+							return Ok(Value::Object(fields));
+						}
+						_ => return Err(self.error("expected ',' or '}'")),
+					}
+				}
+			}
+			Some(c) if c == '-' || c.is_ascii_digit() => {
+				let start = self.pos;
+				while let Some(c) = self.peek().filter(|c| c.is_ascii_digit() || "+-.eE".contains(*c)) {
+					self.pos += c.len_utf8();
+				}
+				self.text[start..self.pos].parse().map(Value::Number).map_err(|_| self.error("invalid number"))
+			}
+			_ => Err(self.error("expected a value")),
+		}
+	}
+
+	fn string(&mut self) -> Result<String, String> {
+		if self.peek() != Some('"') {
+			return Err(self.error("expected a string"));
+		}
+		self.pos += 1;
+		let mut s = String::new();
+		loop {
+			let c = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+			self.pos += c.len_utf8();
+			match c {
+				'"' => return Ok(s),
+				'\\' => {
+					let escaped = self.peek().ok_or_else(|| self.error("unterminated string"))?;
+					self.pos += 1;
+					s.push(match escaped {
+						'"' => '"',
+						'\\' => '\\',
+						'/' => '/',
+						'n' => '\n',
+						't' => '\t',
+						'r' => '\r',
+						'b' => '\u{8}',
+						'f' => '\u{c}',
+						'u' => {
+							let hex = self.text.get(self.pos..self.pos + 4).ok_or_else(|| self.error("invalid escape"))?;
+							self.pos += 4;
+							u32::from_str_radix(hex, 16).ok().and_then(char::from_u32).ok_or_else(|| self.error("invalid escape"))?
+						}
+						_ => return Err(self.error("invalid escape")),
+					});
+				}
+				c => s.push(c),
+			}
+		}
+	}
+}
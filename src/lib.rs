@@ -1,267 +1,3144 @@
 pub mod algorithms;
+pub mod bench;
+mod constraints;
+pub mod daily;
+mod dictionary;
+pub mod dictgen;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod hosts;
+mod json;
+pub mod mask;
+mod matrix;
+mod multi;
+pub mod render;
+pub mod replay;
+pub mod server;
+mod state;
+pub mod stats;
+mod transcript;
 
-use std::collections::HashSet;
+pub use constraints::Constraints;
+pub use dictionary::DictionaryError;
+pub use error::{VerifyError, WordleError};
+pub use matrix::PatternMatrix;
+pub use multi::MultiWordle;
+pub use state::{GameState, Rules, SavedAnswer};
+pub use transcript::GameTranscript;
 
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use dictionary::MAX_WORD_LEN;
+
+#[cfg(feature = "full-dictionary")]
 const DICTIONARY: &str = include_str!("../dictionary.txt");
+#[cfg(any(feature = "full-dictionary", feature = "answers-dictionary"))]
+const ANSWERS: &str = include_str!("../answers.txt");
+
+/// A Wordle engine holding the parsed dictionary with its word frequencies, and the list
+/// of possible answers.
+///
+/// The dictionary is every word that may be guessed. The answers are the (usually much
+/// smaller) set of words the game picks from, which solvers filter as candidates and
+/// benchmarks play against.
+///
+/// Parsing the dictionary is the expensive part of construction, so build one
+/// `Wordle` and reuse it for many games. Cloning is cheap: clones share the same
+/// dictionary storage. The embedded dictionary is only ever parsed once, so
+/// [`Wordle::new`] is cheap after the first call.
+#[derive(Clone)]
+pub struct Wordle {
+	dictionary: Arc<HashMap<&'static str, usize>>,
+	/// The dictionary words, sorted.
+	words: Arc<Vec<&'static str>>,
+	answers: Arc<Vec<&'static str>>,
+	duplicate_rule: DuplicateRule,
+	hard_mode: bool,
+	config: GameConfig,
+	answer_policy: Policy,
+	guess_policy: Policy,
+	unlimited: bool,
+}
+
+/// The shape of a game: how long the words are and how many guesses are allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameConfig {
+	pub word_len: usize,
+	pub max_guesses: usize,
+}
+
+impl Default for GameConfig {
+	/// The official game: five letters, six guesses.
+	fn default() -> Self {
+		Self { word_len: 5, max_guesses: 6 }
+	}
+}
+
+impl Wordle {
+	/// Builds a `Wordle` over the embedded dictionary and answer list. These are checked
+	/// by the tests, so unlike loading a dictionary this cannot fail. They are parsed on
+	/// the first call, and every later one shares the result.
+	///
+	/// Which words are embedded is chosen by cargo features: `full-dictionary` (the
+	/// default) embeds every guessable word with its frequency, and `answers-dictionary`
+	/// only the answers, which are then the only words that may be guessed. Without
+	/// either there is no embedded dictionary and no `Wordle::new`.
+	#[cfg(any(feature = "full-dictionary", feature = "answers-dictionary"))]
+	pub fn new() -> Self {
+		static EMBEDDED: std::sync::OnceLock<Wordle> = std::sync::OnceLock::new();
+		EMBEDDED.get_or_init(|| {
+			#[cfg(feature = "full-dictionary")]
+			let wordle = {
+				let (dictionary, word_len) = dictionary::parse(DICTIONARY).expect("embedded dictionary is valid");
+				Self::with_dictionary(dictionary, word_len)
+					.with_answers(ANSWERS.split_whitespace())
+					.expect("embedded answers are valid")
+			};
+			#[cfg(not(feature = "full-dictionary"))]
+			let wordle = Self::from_words(ANSWERS.split_whitespace()).expect("embedded answers are valid");
+			wordle
+		}).clone()
+	}
+
+	/// Loads a dictionary file with one word per line, each optionally followed by a
+	/// space and its frequency (words without one count as 1). All words must have the
+	/// same length, which becomes the game's word length. Every dictionary word is a
+	/// possible answer unless [`Wordle::with_answers`] says otherwise.
+	///
+	/// The file contents are kept alive for the rest of the program, so load a
+	/// dictionary once and clone the resulting `Wordle` rather than reloading it.
+	pub fn from_dictionary(path: impl AsRef<Path>) -> Result<Self, DictionaryError> {
+		let text = std::fs::read_to_string(path)?;
+		let (dictionary, word_len) = dictionary::parse(Box::leak(text.into_boxed_str()))?;
+		Ok(Self::with_dictionary(dictionary, word_len))
+	}
+
+	/// Builds a `Wordle` from a list of words, each with frequency 1. Words must be
+	/// lowercase letters, all of the same length. Like [`Wordle::from_dictionary`], the words are kept
+	/// alive for the rest of the program, and all of them are possible answers.
+	pub fn from_words<I, S>(words: I) -> Result<Self, DictionaryError>
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		let words = dictionary::leak_words(words, None)?;
+		let word_len = words[0].chars().count();
+		Ok(Self::with_dictionary(words.into_iter().map(|word| (word, 1)).collect(), word_len))
+	}
+
+	/// Replaces the list of possible answers. Answers must be lowercase letters of the
+	/// dictionary's word length; they need not be in the dictionary.
+	pub fn with_answers<I, S>(mut self, answers: I) -> Result<Self, DictionaryError>
+	where
+		I: IntoIterator<Item = S>,
+		S: AsRef<str>,
+	{
+		self.answers = Arc::new(dictionary::leak_words(answers, Some(self.config.word_len))?);
+		Ok(self)
+	}
+
+	fn with_dictionary(dictionary: HashMap<&'static str, usize>, word_len: usize) -> Self {
+		let mut words: Vec<_> = dictionary.keys().copied().collect();
+		words.sort_unstable();
+		let words = Arc::new(words);
+		Self {
+			dictionary: Arc::new(dictionary),
+			answers: Arc::clone(&words),
+			words,
+			duplicate_rule: DuplicateRule::Strict,
+			hard_mode: false,
+			config: GameConfig { word_len, ..GameConfig::default() },
+			answer_policy: Policy::Allow,
+			guess_policy: Policy::Error,
+			unlimited: false,
+		}
+	}
+
+	/// Sets how many guesses a game allows. The word length is fixed by the dictionary.
+	pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+		self.config.max_guesses = max_guesses;
+		self
+	}
+
+	pub fn config(&self) -> GameConfig {
+		self.config
+	}
+
+	/// Enables or disables unlimited guesses. With them `play` ignores `max_guesses` and
+	/// keeps going until the answer is found, so a game's length shows how many guesses
+	/// it really took. A guesser that keeps repeating itself still loses once it has made
+	/// as many guesses as there are dictionary words.
+	pub fn with_unlimited_guesses(mut self, unlimited: bool) -> Self {
+		self.unlimited = unlimited;
+		self
+	}
+
+	pub fn is_unlimited(&self) -> bool {
+		self.unlimited
+	}
+
+	/// How many guesses `play` asks for at most.
+	fn turn_limit(&self) -> usize {
+		match self.unlimited {
+			true => self.words.len().max(self.config.max_guesses),
+			false => self.config.max_guesses,
+		}
+	}
+
+	/// Enables or disables hard mode. In hard mode every guess must reuse the greens in
+	/// place and include the yellows revealed so far; `play` fails on a guess that does
+	/// not.
+	pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+		self.hard_mode = hard_mode;
+		self
+	}
+
+	pub fn is_hard_mode(&self) -> bool {
+		self.hard_mode
+	}
+
+	/// Whether `guess` may be played after `history`: it must be in the dictionary and,
+	/// in hard mode, respect the revealed hints.
+	pub fn is_allowed(&self, guess: &str, history: &[Guess]) -> bool {
+		self.contains(guess) && (!self.hard_mode || uses_hints(guess, history))
+	}
+
+	/// Selects how repeated letters in a guess are marked yellow.
+	pub fn with_duplicate_rule(mut self, rule: DuplicateRule) -> Self {
+		self.duplicate_rule = rule;
+		self
+	}
+
+	pub fn duplicate_rule(&self) -> DuplicateRule {
+		self.duplicate_rule
+	}
+
+	/// Selects what `play` does with an answer that is not in the answer list. Such
+	/// answers are allowed by default.
+	pub fn with_answer_policy(mut self, policy: Policy) -> Self {
+		self.answer_policy = policy;
+		self
+	}
+
+	/// Selects what `play` does with a guess that is not in the dictionary. Such guesses
+	/// are errors by default.
+	pub fn with_guess_policy(mut self, policy: Policy) -> Self {
+		self.guess_policy = policy;
+		self
+	}
+
+	/// Whether `word` is in the dictionary and so may be guessed.
+	pub fn contains(&self, word: &str) -> bool {
+		self.dictionary.contains_key(word)
+	}
+
+	/// The dictionary words, in alphabetical order.
+	pub fn words(&self) -> &[&'static str] {
+		&self.words
+	}
+
+	/// How often `word` occurs according to the dictionary, if it is in the dictionary.
+	pub fn frequency(&self, word: &str) -> Option<usize> {
+		self.dictionary.get(word).copied()
+	}
+
+	/// Iterates over the dictionary words together with their frequencies, in no
+	/// particular order.
+	pub fn words_with_frequency(&self) -> impl Iterator<Item = (&'static str, usize)> + '_ {
+		self.dictionary.iter().map(|(&word, &count)| (word, count))
+	}
+	
+	/// Plays one game against `answer` and returns how it went.
+	///
+	/// Fails if the guesser makes a guess that is not allowed: one of the wrong length,
+	/// one missing from the dictionary (unless it is the answer), or in hard mode one
+	/// that ignores the hints. [`Wordle::with_answer_policy`] and
+	/// [`Wordle::with_guess_policy`] choose what happens with answers missing from the
+	/// answer list and guesses missing from the dictionary.
+	///
+	/// The guesser is asked for at most `max_guesses` guesses (6 by default), unless
+	/// guesses are [unlimited](Wordle::with_unlimited_guesses). It is asked that many
+	/// times only when the game is lost or won on the last turn; a win on turn `n` means
+	/// `guess` was called `n` times.
+	pub fn play<G: Guesser>(&self, answer: &str, guesser: G) -> Result<GameResult, WordleError> {
+		self.play_with_probes(answer, guesser, 0)
+	}
+
+	/// Like [`Wordle::play`], but echoes the answer back alongside the result, which
+	/// is handy when collecting results for many answers.
+	pub fn play_logged<'a, G: Guesser>(&self, answer: &'a str, guesser: G) -> Result<(&'a str, Option<usize>), WordleError> {
+		Ok((answer, self.play(answer, guesser)?.solved_in()))
+	}
+
+	/// Like [`Wordle::play`], but the first `probes` guesses are probes: they use up a
+	/// turn and their feedback is added to the history, but they never win the game,
+	/// even when they equal the answer. Useful for practicing with information-only
+	/// guesses.
+	pub fn play_with_probes<G: Guesser>(&self, answer: &str, guesser: G, probes: usize) -> Result<GameResult, WordleError> {
+		self.check_answer(answer)?;
+		self.run(hosts::Honest::new(self, answer), guesser, probes)
+	}
+
+	/// Like [`Wordle::play`], but the feedback comes from `host`, which need not have
+	/// fixed an answer up front. The result's answer is the one the host settled on.
+	pub fn play_against<H: Host, G: Guesser>(&self, host: H, guesser: G) -> Result<GameResult, WordleError> {
+		self.run(host, guesser, 0)
+	}
+
+	fn run<H: Host, G: Guesser>(&self, mut host: H, mut guesser: G, probes: usize) -> Result<GameResult, WordleError> {
+		let mut history = Vec::new();
+		for i in 0..self.turn_limit() {
+			let guess = guesser.guess(&history[..]);
+			if self.turn(&mut host, guess, i >= probes, &mut history)? {
+				return Ok(GameResult { answer: host.answer().to_string(), history, won: true });
+			}
+		}
+		Ok(GameResult { answer: host.answer().to_string(), history, won: false })
+	}
+
+	/// Like [`Wordle::play`], for a guesser that has to wait for its guesses. Works with
+	/// any async runtime, since the game itself never waits on anything else.
+	pub async fn play_async<G: AsyncGuesser>(&self, answer: &str, mut guesser: G) -> Result<GameResult, WordleError> {
+		self.check_answer(answer)?;
+		let mut host = hosts::Honest::new(self, answer);
+		let mut history = Vec::new();
+		for _ in 0..self.turn_limit() {
+			let guess = guesser.guess(&history[..]).await;
+			if self.turn(&mut host, guess, true, &mut history)? {
+				return Ok(GameResult { answer: answer.to_string(), history, won: true });
+			}
+		}
+		Ok(GameResult { answer: answer.to_string(), history, won: false })
+	}
+
+	/// Applies the answer policy to `answer`.
+	fn check_answer(&self, answer: &str) -> Result<(), WordleError> {
+		if self.answer_policy == Policy::Allow || self.answers.contains(&answer) {
+			return Ok(());
+		}
+		if self.answer_policy == Policy::Warn {
+			eprintln!("warning: answer '{}' is not in the answer list", answer);
+			return Ok(());
+		}
+		Err(WordleError::UnknownAnswer { answer: answer.to_string() })
+	}
+
+	/// Checks `guess`, gets its feedback from `host` and adds it to `history`. Returns
+	/// whether it won the game, which only counts if `can_win`.
+	fn turn<H: Host>(&self, host: &mut H, guess: String, can_win: bool, history: &mut Vec<Guess<'static>>) -> Result<bool, WordleError> {
+		if guess.chars().count() != self.config.word_len {
+			return Err(WordleError::WrongLength { guess, expected: self.config.word_len });
+		}
+		let correctness = host.respond(&guess);
+		// a winning guess counts even if it is not in the dictionary
+		let won = can_win && correctness.iter().all(|&c| c == Correctness::Correct);
+		if !won {
+			self.validate(&guess, history)?;
+		}
+		history.push(Guess {
+			word: Cow::Owned(guess),
+			mask: correctness
+		});
+		Ok(won)
+	}
+
+	/// Iterates over the possible answers that are consistent with every guess in
+	/// `history`.
+	pub fn candidates<'a>(&'a self, history: &'a [Guess<'a>]) -> impl Iterator<Item = &'static str> + 'a {
+		self.answers.iter().copied().filter(move |word| self.is_consistent(word, history))
+	}
+
+	/// The possible answers, in their original order.
+	pub fn answers(&self) -> &[&'static str] {
+		&self.answers
+	}
+
+	/// The `n` best next guesses after `history`, as picked by [`algorithms::Entropy`], best
+	/// first.
+	pub fn hint(&self, history: &[Guess], n: usize) -> Vec<ScoredGuess> {
+		let mut entropy = algorithms::Entropy::new(self);
+		let suggestions = entropy.suggest_top(history, n);
+		suggestions.into_iter().map(|(word, _)| self.score_among(&word, entropy.remaining())).collect()
+	}
+
+	/// Scores `guess` as the next guess after `history`, the same way [`Wordle::hint`]
+	/// scores its suggestions. The guess does not have to be in the dictionary.
+	pub fn score(&self, guess: &str, history: &[Guess]) -> ScoredGuess {
+		let candidates: Vec<_> = self.candidates(history).collect();
+		self.score_among(guess, &candidates)
+	}
+
+	fn score_among(&self, guess: &str, candidates: &[&str]) -> ScoredGuess {
+		let mut buckets: HashMap<PackedCorrectness, usize> = HashMap::new();
+		for candidate in candidates {
+			*buckets.entry(PackedCorrectness::compute(candidate, guess, self.duplicate_rule)).or_default() += 1;
+		}
+		let total = candidates.len().max(1) as f64;
+		let bits = buckets.values().map(|&count| {
+			let p = count as f64 / total;
+			-p * p.log2()
+		}).sum();
+		// each of the `count` answers giving a pattern leaves `count` answers, unless it wins
+		let (mut left, mut worst) = (0, 0);
+		for (pattern, count) in buckets {
+			if pattern != PackedCorrectness::correct(self.config.word_len) {
+				left += count * count;
+				worst = worst.max(count);
+			}
+		}
+		ScoredGuess {
+			word: guess.to_string(),
+			bits,
+			is_candidate: candidates.contains(&guess),
+			expected_remaining: left as f64 / total,
+			worst_remaining: worst,
+		}
+	}
+
+	/// Checks that `history` could have come from a real game against `answer` under
+	/// these rules: every mask is the feedback the answer gives, every guess but the
+	/// answer is in the dictionary, hard mode hints are respected if hard mode is on, the
+	/// game stops once the answer is found, and no more guesses are made than allowed.
+	pub fn verify(&self, answer: &str, history: &[Guess]) -> Result<(), VerifyError> {
+		if history.len() > self.turn_limit() {
+			return Err(VerifyError::TooManyGuesses { guesses: history.len(), max_guesses: self.turn_limit() });
+		}
+		for (i, guess) in history.iter().enumerate() {
+			let turn = i + 1;
+			let word = guess.word.to_string();
+			if i > 0 && history[i - 1].word == answer {
+				return Err(VerifyError::PlayedAfterWin { turn: i });
+			}
+			if word.chars().count() != self.config.word_len {
+				return Err(VerifyError::WrongLength { turn, guess: word, expected: self.config.word_len });
+			}
+			let expected = Correctness::compute_with(answer, &word, self.duplicate_rule);
+			if guess.mask != expected {
+				return Err(VerifyError::WrongMask { turn, guess: word, mask: guess.mask.clone(), expected });
+			}
+			if word == answer {
+				continue;
+			}
+			if !self.contains(&word) {
+				return Err(VerifyError::NotInDictionary { turn, guess: word });
+			}
+			if self.hard_mode && !uses_hints(&word, &history[..i]) {
+				return Err(VerifyError::IgnoresHints { turn, guess: word });
+			}
+		}
+		Ok(())
+	}
+
+	/// Whether `word` could be the answer given the feedback in `history`.
+	pub fn is_consistent(&self, word: &str, history: &[Guess]) -> bool {
+		history.iter().all(|h| h.matches_with(word, self.duplicate_rule))
+	}
+}
+
+/// A suggested guess returned by [`Wordle::hint`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoredGuess {
+	pub word: String,
+	/// The expected information the guess gives, in bits.
+	pub bits: f64,
+	/// Whether the guess could itself be the answer.
+	pub is_candidate: bool,
+	/// How many possible answers are left after the guess, on average and at most. A
+	/// guess that wins leaves none.
+	pub expected_remaining: f64,
+	pub worst_remaining: usize,
+}
+
+/// Whether `guess` keeps every green from `history` in place and contains every revealed
+/// letter at least as many times as it was marked green or yellow in a single guess.
+fn uses_hints(guess: &str, history: &[Guess]) -> bool {
+	LetterStates::from_history(history).uses_hints(guess)
+}
+
+/// What a history of guesses reveals about each letter: what the on-screen keyboard
+/// shows, and what hard mode requires of the next guess.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LetterStates {
+	/// The letter known to be at each position.
+	greens: Vec<Option<char>>,
+	/// For each guessed letter, how many times the answer has it at least.
+	counts: HashMap<char, usize>,
+}
+
+/// What is known about one letter, as shown on the keyboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LetterState {
+	/// Green in some guess.
+	Correct,
+	/// In the answer, but not yet found in place.
+	Present,
+	/// Not in the answer.
+	Absent,
+	/// Not guessed yet.
+	Unknown,
+}
+
+impl LetterStates {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn from_history(history: &[Guess]) -> Self {
+		let mut states = Self::new();
+		for guess in history {
+			states.update(guess);
+		}
+		states
+	}
+
+	/// Adds what `guess` reveals.
+	pub fn update(&mut self, guess: &Guess) {
+		for (i, (letter, &c)) in guess.word.chars().zip(&guess.mask).enumerate() {
+			if c == Correctness::Correct {
+				if self.greens.len() <= i {
+					self.greens.resize(i + 1, None);
+				}
+				self.greens[i] = Some(letter);
+			}
+			let revealed = guess.word.chars().zip(&guess.mask)
+				.filter(|&(l, &c)| l == letter && c != Correctness::Wrong)
+				.count();
+			let min = self.counts.entry(letter).or_default();
+			*min = (*min).max(revealed);
+		}
+	}
+
+	pub fn state(&self, letter: char) -> LetterState {
+		match self.counts.get(&letter) {
+			_ if self.greens.contains(&Some(letter)) => LetterState::Correct,
+			Some(0) => LetterState::Absent,
+			Some(_) => LetterState::Present,
+			None => LetterState::Unknown,
+		}
+	}
+
+	/// The letter known to be at `position`, if any.
+	pub fn green(&self, position: usize) -> Option<char> {
+		self.greens.get(position).copied().flatten()
+	}
+
+	/// How many times the answer has `letter` at least.
+	pub fn min_count(&self, letter: char) -> usize {
+		self.counts.get(&letter).copied().unwrap_or(0)
+	}
+
+	/// Whether `guess` may be played in hard mode: it keeps every green in place and
+	/// contains every revealed letter at least [`LetterStates::min_count`] times.
+	pub fn uses_hints(&self, guess: &str) -> bool {
+		let letters: Vec<char> = guess.chars().collect();
+		let greens_kept = self.greens.iter().enumerate()
+			.all(|(i, green)| green.is_none_or(|g| letters.get(i) == Some(&g)));
+		greens_kept && self.counts.iter()
+			.all(|(&letter, &min)| letters.iter().filter(|&&l| l == letter).count() >= min)
+	}
+}
+
+#[cfg(any(feature = "full-dictionary", feature = "answers-dictionary"))]
+impl Default for Wordle {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Correctness {
+    Correct,
+    Misplaced,
+    Wrong,
+}
+
+/// What a game does with a word that is not on the list it should come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+	/// Fails the game.
+	Error,
+	/// Plays on after printing a warning to stderr.
+	Warn,
+	/// Plays on silently.
+	Allow,
+}
+
+/// How a guessed letter that is not green is marked when the letter repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DuplicateRule {
+	/// A letter is yellow only while the answer has unmatched occurrences of it left,
+	/// as in the official game.
+	#[default]
+	Strict,
+	/// A letter is yellow whenever the answer contains it at all, however many times
+	/// it is repeated in the guess.
+	Lenient,
+}
+
+impl Correctness {
+	/// Every possible mask of a word with `word_len` letters (243 of them for five), in
+	/// the order of [`PackedCorrectness::index`].
+	pub fn patterns(word_len: usize) -> impl Iterator<Item = Vec<Self>> {
+		assert!(word_len <= MAX_WORD_LEN, "words have at most {} letters", MAX_WORD_LEN);
+		(0..PackedCorrectness::count(word_len)).map(move |i| PackedCorrectness(i as u16).to_mask(word_len))
+	}
+
+	/// Computes the feedback for `guess` when the answer is `answer`, using the official
+	/// duplicate-letter rule.
+	pub fn compute(answer: &str, guess: &str) -> Vec<Self> {
+		Self::compute_with(answer, guess, DuplicateRule::Strict)
+	}
+
+	/// Like [`Correctness::compute`], with the given duplicate-letter rule.
+	pub fn compute_with(answer: &str, guess: &str, rule: DuplicateRule) -> Vec<Self> {
+		let mut c = vec![Correctness::Wrong; guess.chars().count()];
+		Self::compute_into(answer, guess, rule, &mut c);
+		c
+	}
+
+	/// Writes the feedback into `c`, which must have one entry per letter. Letters are
+	/// `char`s, so words with diacritics work, but combining characters count as letters
+	/// of their own.
+	fn compute_into(answer: &str, guess: &str, rule: DuplicateRule, c: &mut [Self]) {
+		let len = guess.chars().count();
+		assert_eq!(answer.chars().count(), len, "answer and guess differ in length");
+		assert_eq!(len, c.len());
+		assert!(len <= MAX_WORD_LEN, "words are longer than {} letters", MAX_WORD_LEN);
+		c.fill(Correctness::Wrong);
+		// mark things green
+		for (i, (a, g)) in answer.chars().zip(guess.chars()).enumerate() {
+			if a == g {
+				c[i] = Correctness::Correct;
+			}
+		}
+		// mark things as yellow
+		let mut used = [false; MAX_WORD_LEN];
+		for (i, &c) in c.iter().enumerate() {
+			if c == Correctness::Correct {
+				used[i] = true;
+			}
+		}
+		for (i, g) in guess.chars().enumerate() {
+			if c[i] == Correctness::Correct {
+				// Already marked as green
+				continue;
+			}
+			if answer.chars().enumerate().any(|(i, a)| {
+				if a == g && rule == DuplicateRule::Lenient {
+					return true;
+				}
+				if a == g && !used[i] {
+					used[i] = true;
+					return true;
+				}
+				false
+			}) {
+				c[i] = Correctness::Misplaced;
+			}
+		}
+	}
+}
+
+/// A correctness pattern packed into a base-3 number, with the first letter in the least
+/// significant digit (wrong = 0, misplaced = 1, correct = 2).
+///
+/// The `3^n` possible patterns of `n`-letter words map to `0..3^n`, so packed patterns
+/// can index flat arrays. The packed form does not record the word length; methods that
+/// need it take it as an argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PackedCorrectness(u16);
+
+impl PackedCorrectness {
+	/// The number of distinct patterns of words with `word_len` letters.
+	pub fn count(word_len: usize) -> usize {
+		3usize.pow(word_len as u32)
+	}
+
+	pub fn compute(answer: &str, guess: &str, rule: DuplicateRule) -> Self {
+		let mut mask = [Correctness::Wrong; MAX_WORD_LEN];
+		let mask = &mut mask[..guess.chars().count().min(MAX_WORD_LEN)];
+		Correctness::compute_into(answer, guess, rule, mask);
+		Self::from(&*mask)
+	}
+
+	/// The all-green pattern of a word with `word_len` letters.
+	pub fn correct(word_len: usize) -> Self {
+		Self((Self::count(word_len) - 1) as u16)
+	}
+
+	/// The pattern as an index into `0..PackedCorrectness::count(word_len)`.
+	pub fn index(self) -> usize {
+		self.0 as usize
+	}
+
+	/// The pattern with this index, or `None` if it is out of range for `word_len` letters.
+	pub fn from_index(index: usize, word_len: usize) -> Option<Self> {
+		(word_len <= MAX_WORD_LEN && index < Self::count(word_len)).then_some(Self(index as u16))
+	}
+
+	/// Unpacks the pattern of a word with `word_len` letters.
+	pub fn to_mask(self, word_len: usize) -> Vec<Correctness> {
+		let mut rest = self.0;
+		(0..word_len).map(|_| {
+			let c = match rest % 3 {
+				0 => Correctness::Wrong,
+				1 => Correctness::Misplaced,
+				_ => Correctness::Correct,
+			};
+			rest /= 3;
+			c
+		}).collect()
+	}
+}
+
+impl From<&[Correctness]> for PackedCorrectness {
+	fn from(mask: &[Correctness]) -> Self {
+		assert!(mask.len() <= MAX_WORD_LEN, "masks are at most {} letters", MAX_WORD_LEN);
+		Self(mask.iter().rev().fold(0, |packed, c| {
+			packed * 3 + match c {
+				Correctness::Wrong => 0,
+				Correctness::Misplaced => 1,
+				Correctness::Correct => 2,
+			}
+		}))
+	}
+}
+
+impl From<Vec<Correctness>> for PackedCorrectness {
+	fn from(mask: Vec<Correctness>) -> Self {
+		Self::from(&mask[..])
+	}
+}
+
+/// A guessed word and the feedback it got. The word can be borrowed or owned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Guess<'a> {
+    pub word: Cow<'a, str>,
+    pub mask: Vec<Correctness>,
+}
+
+impl Guess<'_> {
+	/// Whether `word` could be the answer given this guess and its feedback.
+	pub fn matches(&self, word: &str) -> bool {
+		self.matches_with(word, DuplicateRule::Strict)
+	}
+
+	/// Like [`Guess::matches`], with the given duplicate-letter rule.
+	pub fn matches_with(&self, word: &str, rule: DuplicateRule) -> bool {
+		self.mask.len() == self.word.chars().count()
+			&& PackedCorrectness::compute(word, &self.word, rule) == PackedCorrectness::from(&self.mask[..])
+	}
+}
+
+/// The compact form of a guess: the word, a colon and its mask, with C for correct, M for
+/// misplaced and W for wrong letters, e.g. "crane:WCMMW".
+impl fmt::Display for Guess<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{}:{}", self.word, mask::display(&self.mask, mask::Style::Compact))
+	}
+}
+
+impl std::str::FromStr for Guess<'static> {
+	type Err = String;
+
+	/// Parses the compact form written by `Display`. The mask may also be written in the
+	/// other styles [`mask::parse`] accepts.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let (word, mask) = s.split_once(':').ok_or_else(|| format!("'{}' is not like 'crane:WCMMW'", s))?;
+		let mask = mask::parse(mask).ok_or_else(|| format!("'{}' has an invalid mask", s))?;
+		if mask.len() != word.chars().count() {
+			return Err(format!("'{}' has a mask of the wrong length", s));
+		}
+		Ok(Guess { word: Cow::Owned(word.to_string()), mask })
+	}
+}
+
+/// Keeps the `candidates` that match every guess in `history`.
+pub fn filter_candidates<'a, I>(candidates: I, history: &'a [Guess<'a>]) -> impl Iterator<Item = I::Item> + 'a
+where
+	I: IntoIterator + 'a,
+	I::Item: AsRef<str>,
+{
+	candidates.into_iter().filter(move |word| history.iter().all(|h| h.matches(word.as_ref())))
+}
+
+/// The possible answers still consistent with a history, for guessers to own.
+///
+/// Each [`CandidateSet::update`] only filters the remaining answers by the guesses it has
+/// not seen yet, so a turn costs time in proportion to the answers still left rather
+/// than to the whole answer list.
+///
+/// An answer may be any dictionary word, even one that is not on the answer list. Once
+/// no answer fits the history, the set falls back to the dictionary words that do.
+#[derive(Debug, Clone)]
+pub struct CandidateSet {
+	rule: DuplicateRule,
+	answers: Arc<[&'static str]>,
+	words: Arc<Vec<&'static str>>,
+	remaining: Vec<&'static str>,
+	/// The guesses `remaining` has been filtered by.
+	seen: Vec<Guess<'static>>,
+}
+
+impl CandidateSet {
+	/// All the answers of `wordle`, sorted.
+	pub fn new(wordle: &Wordle) -> Self {
+		let mut answers = wordle.answers().to_vec();
+		answers.sort_unstable();
+		Self {
+			rule: wordle.duplicate_rule,
+			remaining: answers.clone(),
+			answers: answers.into(),
+			words: Arc::clone(&wordle.words),
+			seen: Vec::new(),
+		}
+	}
+
+	/// Narrows the set down to the answers consistent with `history`, and returns them.
+	/// If none is, returns the dictionary words that are instead, which is empty only if
+	/// the history contradicts itself.
+	///
+	/// If `history` does not continue the history of the last update (as in a new game,
+	/// or on another board of a multi-board game), the set starts over from all answers.
+	pub fn update(&mut self, history: &[Guess]) -> &[&'static str] {
+		if !history.starts_with(&self.seen) {
+			self.remaining.clear();
+			self.remaining.extend_from_slice(&self.answers);
+			self.seen.clear();
+		}
+		let was_empty = self.remaining.is_empty();
+		for guess in &history[self.seen.len()..] {
+			self.remaining.retain(|word| guess.matches_with(word, self.rule));
+			self.seen.push(Guess { word: Cow::Owned(guess.word.to_string()), mask: guess.mask.clone() });
+		}
+		if self.remaining.is_empty() && !was_empty {
+			let rule = self.rule;
+			self.remaining.extend(self.words.iter().copied().filter(|word| history.iter().all(|h| h.matches_with(word, rule))));
+		}
+		&self.remaining
+	}
+
+	/// Goes back to all answers, as for a new game.
+	pub fn reset(&mut self) {
+		self.update(&[]);
+	}
+
+	/// The words [`CandidateSet::update`] last returned, sorted.
+	pub fn remaining(&self) -> &[&'static str] {
+		&self.remaining
+	}
+
+	pub fn len(&self) -> usize {
+		self.remaining.len()
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.remaining.is_empty()
+	}
+
+	/// Whether `word` is still a possible answer.
+	pub fn contains(&self, word: &str) -> bool {
+		self.remaining.binary_search(&word).is_ok()
+	}
+}
+
+/// The outcome of one game played by [`Wordle::play`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameResult {
+	pub answer: String,
+	/// Every guess made, in order, including the winning one.
+	pub history: Vec<Guess<'static>>,
+	pub won: bool,
+}
+
+impl GameResult {
+	/// The number of guesses made.
+	pub fn turns(&self) -> usize {
+		self.history.len()
+	}
+
+	/// The number of guesses it took to win, or `None` if the game was lost.
+	pub fn solved_in(&self) -> Option<usize> {
+		self.won.then(|| self.turns())
+	}
+
+	/// Renders the emoji grid people paste into chat, headed by e.g. "Wordle 245 3/6" (or
+	/// "Wordle X/6" for a loss). The puzzle number is left out if `puzzle` is `None`.
+	pub fn share_grid(&self, puzzle: Option<usize>, max_guesses: usize) -> String {
+		let score = self.solved_in().map_or("X".to_string(), |n| n.to_string());
+		let mut grid = match puzzle {
+			Some(n) => format!("Wordle {} {}/{}\n", n, score, max_guesses),
+			None => format!("Wordle {}/{}\n", score, max_guesses),
+		};
+		for guess in &self.history {
+			grid.push_str(&format!("\n{}", mask::display(&guess.mask, mask::Style::Emoji)));
+		}
+		grid
+	}
+}
+
+/// Decides the feedback for each guess in a game played by [`Wordle::play_against`].
+///
+/// An honest host ([`hosts::Honest`]) compares guesses against a fixed answer. Other hosts
+/// may pick the feedback as they go, as long as it stays consistent with some answer.
+pub trait Host {
+	/// The feedback for `guess`, with one entry per letter.
+	fn respond(&mut self, guess: &str) -> Vec<Correctness>;
+
+	/// The answer the host has settled on, or one it could still settle on.
+	fn answer(&self) -> &str;
+}
+
+/// The rules of a Wordle-like game: how guesses are marked, which are allowed and what
+/// wins. [`Wordle`] has the official rules; other word games can implement this trait to
+/// reuse [`WordGame::play`], the history types and [`bench`].
+pub trait WordGame {
+	fn config(&self) -> GameConfig;
+
+	/// The feedback for `guess` when the answer is `answer`, both of the configured length.
+	fn feedback(&self, answer: &str, guess: &str) -> Vec<Correctness>;
+
+	/// Checks a guess of the right length that did not win, given the guesses before it.
+	fn validate(&self, guess: &str, history: &[Guess]) -> Result<(), WordleError>;
+
+	/// Whether a guess with the feedback `mask` wins the game. By default it must be all
+	/// green.
+	fn is_win(&self, mask: &[Correctness]) -> bool {
+		mask.iter().all(|&c| c == Correctness::Correct)
+	}
+
+	/// Plays one game against `answer` and returns how it went, as [`Wordle::play`] does.
+	fn play<G: Guesser>(&self, answer: &str, mut guesser: G) -> Result<GameResult, WordleError> {
+		let GameConfig { word_len, max_guesses } = self.config();
+		let mut history = Vec::new();
+		for _ in 0..max_guesses {
+			let guess = guesser.guess(&history[..]);
+			if guess.chars().count() != word_len {
+				return Err(WordleError::WrongLength { guess, expected: word_len });
+			}
+			let mask = self.feedback(answer, &guess);
+			let won = self.is_win(&mask);
+			if !won {
+				self.validate(&guess, &history)?;
+			}
+			history.push(Guess { word: Cow::Owned(guess), mask });
+			if won {
+				return Ok(GameResult { answer: answer.to_string(), history, won: true });
+			}
+		}
+		Ok(GameResult { answer: answer.to_string(), history, won: false })
+	}
+}
+
+impl WordGame for Wordle {
+	fn config(&self) -> GameConfig {
+		self.config
+	}
+
+	fn feedback(&self, answer: &str, guess: &str) -> Vec<Correctness> {
+		Correctness::compute_with(answer, guess, self.duplicate_rule)
+	}
+
+	/// A guess must be in the dictionary, unless the guess policy says otherwise, and in
+	/// hard mode use the revealed hints. A winning guess counts even if it is not in the
+	/// dictionary.
+	fn validate(&self, guess: &str, history: &[Guess]) -> Result<(), WordleError> {
+		if !self.dictionary.contains_key(guess) {
+			match self.guess_policy {
+				Policy::Error => return Err(WordleError::NotInDictionary { guess: guess.to_string() }),
+				Policy::Warn => eprintln!("warning: guess '{}' is not in the dictionary", guess),
+				Policy::Allow => {}
+			}
+		}
+		if self.hard_mode && !uses_hints(guess, history) {
+			return Err(WordleError::IgnoresHints { guess: guess.to_string() });
+		}
+		Ok(())
+	}
+
+	fn play<G: Guesser>(&self, answer: &str, guesser: G) -> Result<GameResult, WordleError> {
+		Wordle::play(self, answer, guesser)
+	}
+}
+
+pub trait Guesser {
+	fn guess(&mut self, history: &[Guess]) -> String;
+
+	/// Like [`Guesser::guess`], but returns by `deadline` if the guesser can stop early,
+	/// with the best guess it found until then. By default the deadline is ignored.
+	fn guess_by(&mut self, history: &[Guess], _deadline: Instant) -> String {
+		self.guess(history)
+	}
+
+	/// Prepares for a new game. Guessers that carry state from one turn to the next
+	/// should clear it here, so that one instance can play many games, as in
+	/// [`bench::run`]. Does nothing by default.
+	fn reset(&mut self) {}
+
+	/// Picks one guess for several boards played at once, as in [`MultiWordle`], given
+	/// the history of each board. A board is solved once its last guess is all green.
+	///
+	/// By default this guesses for the first unsolved board only. Override it to play
+	/// the boards together.
+	fn guess_multi(&mut self, histories: &[&[Guess]]) -> String {
+		let board = histories.iter()
+			.find(|history| !is_solved(history))
+			.expect("some board is unsolved");
+		self.guess(board)
+	}
+
+	/// Where the guesser's time went since it was built, for guessers that keep count.
+	/// [`Guesser::reset`] does not clear it. All zero by default.
+	fn profile(&self) -> Profile {
+		Profile::default()
+	}
+}
+
+/// Where a guesser's time went. See [`Guesser::profile`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Profile {
+	/// Narrowing down the candidates after each guess.
+	pub filtering: Duration,
+	/// Scoring the possible guesses.
+	pub scoring: Duration,
+}
+
+impl Profile {
+	/// The time spent since `earlier` was taken.
+	pub fn since(self, earlier: Profile) -> Profile {
+		Profile {
+			filtering: self.filtering.saturating_sub(earlier.filtering),
+			scoring: self.scoring.saturating_sub(earlier.scoring),
+		}
+	}
+}
+
+impl std::ops::AddAssign for Profile {
+	fn add_assign(&mut self, other: Profile) {
+		self.filtering += other.filtering;
+		self.scoring += other.scoring;
+	}
+}
+
+/// Like [`Guesser`], for guessers that have to wait for their guesses, such as ones
+/// asking a remote service. Played by [`Wordle::play_async`].
+pub trait AsyncGuesser {
+	fn guess(&mut self, history: &[Guess]) -> impl Future<Output = String>;
+}
+
+impl<G: AsyncGuesser + ?Sized> AsyncGuesser for &mut G {
+	fn guess(&mut self, history: &[Guess]) -> impl Future<Output = String> {
+		(**self).guess(history)
+	}
+}
+
+/// Whether the last guess in `history` is all green.
+pub fn is_solved(history: &[Guess]) -> bool {
+	history.last().is_some_and(|guess| guess.mask.iter().all(|&c| c == Correctness::Correct))
+}
+
+impl<G: Guesser + ?Sized> Guesser for &mut G {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		(**self).guess(history)
+	}
+
+	fn guess_by(&mut self, history: &[Guess], deadline: Instant) -> String {
+		(**self).guess_by(history, deadline)
+	}
+
+	fn guess_multi(&mut self, histories: &[&[Guess]]) -> String {
+		(**self).guess_multi(histories)
+	}
+
+	fn reset(&mut self) {
+		(**self).reset()
+	}
+
+	fn profile(&self) -> Profile {
+		(**self).profile()
+	}
+}
+
+impl<G: Guesser + ?Sized> Guesser for Box<G> {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		(**self).guess(history)
+	}
+
+	fn guess_by(&mut self, history: &[Guess], deadline: Instant) -> String {
+		(**self).guess_by(history, deadline)
+	}
+
+	fn guess_multi(&mut self, histories: &[&[Guess]]) -> String {
+		(**self).guess_multi(histories)
+	}
+
+	fn reset(&mut self) {
+		(**self).reset()
+	}
+
+	fn profile(&self) -> Profile {
+		(**self).profile()
+	}
+}
+
+impl Guesser for fn(history: &[Guess]) -> String {
+	fn guess(&mut self, history: &[Guess]) -> String {
+		(*self)(history)
+	}
+}
+
+/// Returns, for each of the `word_len` positions, the letters that can still appear there
+/// given `history`.
+///
+/// A green fixes its position to a single letter. A yellow removes the letter from the
+/// position it was guessed in. A gray removes the letter from every position, unless the
+/// same guess also marks that letter green or yellow elsewhere, in which case it is only
+/// removed from the position it was guessed in.
+///
+/// Positions start out with the letters a to z and every letter guessed so far, so other
+/// letters, such as ones with diacritics, are left out until they have been guessed.
+pub fn position_candidates(history: &[Guess], word_len: usize) -> Vec<HashSet<char>> {
+	let alphabet: HashSet<char> = ('a'..='z').chain(history.iter().flat_map(|guess| guess.word.chars())).collect();
+	let mut candidates = vec![alphabet; word_len];
+	for guess in history {
+		for (i, (g, &m)) in guess.word.chars().zip(guess.mask.iter()).enumerate() {
+			match m {
+				Correctness::Correct => {
+					candidates[i] = HashSet::from([g]);
+				}
+				Correctness::Misplaced => {
+					candidates[i].remove(&g);
+				}
+				Correctness::Wrong => {
+					let present_elsewhere = guess.word.chars().zip(guess.mask.iter()).any(|(w, &m)| {
+						w == g && m != Correctness::Wrong
+					});
+					if present_elsewhere {
+						candidates[i].remove(&g);
+					}
+					else {
+						for set in candidates.iter_mut() {
+							if set.len() > 1 {
+								set.remove(&g);
+							}
+						}
+					}
+				}
+			}
+		}
+	}
+	candidates
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{Guess, Guesser, Wordle};
+
+	/// Guesses the words in order, whatever the feedback.
+	struct Scripted(&'static [&'static str]);
+
+	impl Guesser for Scripted {
+		fn guess(&mut self, history: &[Guess]) -> String {
+			self.0[history.len()].to_string()
+		}
+	}
+
+	/// A dictionary small enough for the slow guessers to play every answer of, and its
+	/// words.
+	fn small() -> (Wordle, [&'static str; 8]) {
+		let words = ["cigar", "rebut", "sissy", "humph", "awake", "blush", "focal", "evade"];
+		(Wordle::from_words(words).unwrap(), words)
+	}
+
+	macro_rules! mask {
+		(C) => { $crate::Correctness::Correct };
+		(M) => { $crate::Correctness::Misplaced };
+		(W) => { $crate::Correctness::Wrong };
+		($($c:tt)+) => {vec![
+			$(mask!($c)),+
+		]}
+	}
+
+	mod wordle {
+		use crate::{DictionaryError, Guess, Wordle};
+		#[cfg(feature = "full-dictionary")]
+		use crate::{Correctness, GameConfig};
+		#[cfg(feature = "full-dictionary")]
+		use std::sync::Arc;
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn embedded_dictionary_is_parsed_once() {
+			let (a, b) = (Wordle::new(), Wordle::default());
+			assert!(Arc::ptr_eq(&a.dictionary, &b.dictionary));
+			assert!(Arc::ptr_eq(&a.answers, &b.answers));
+			assert_eq!(a.words().len(), a.dictionary.len());
+			assert!(a.words().windows(2).all(|pair| pair[0] < pair[1]));
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn clone_shares_dictionary() {
+			let w = Wordle::new();
+			let c = w.clone();
+			assert!(Arc::ptr_eq(&w.dictionary, &c.dictionary));
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn candidates_match_history() {
+			let w = Wordle::new();
+			let history = [Guess { word: "slate".into(), mask: Correctness::compute("cigar", "slate") }];
+			let candidates: Vec<_> = w.candidates(&history).collect();
+			assert!(candidates.contains(&"cigar"));
+			assert!(candidates.iter().all(|word| w.answers().contains(word)));
+			assert!(!candidates.contains(&"slate"));
+			assert!(candidates.iter().all(|word| w.is_consistent(word, &history)));
+		}
+
+		#[test]
+		fn from_words() {
+			let w = Wordle::from_words(["cigar", "rebut", "sissy"]).unwrap();
+			assert!(w.contains("rebut"));
+			assert!(!w.contains("crane"));
+			assert_eq!(w.words_with_frequency().count(), 3);
+			assert_eq!(w.frequency("rebut"), Some(1));
+			assert_eq!(w.frequency("crane"), None);
+		}
+
+		#[test]
+		fn from_words_rejects_invalid() {
+			assert!(matches!(
+				Wordle::from_words(["cigar", "toolong"]),
+				Err(DictionaryError::InvalidWord { line: 2, .. })
+			));
+			assert!(matches!(Wordle::from_words(Vec::<&str>::new()), Err(DictionaryError::Empty)));
+		}
+
+		#[test]
+		fn from_dictionary_file() {
+			let path = std::env::temp_dir().join("wordle_from_dictionary_file.txt");
+			std::fs::write(&path, "cigar 10\nrebut\n\nsissy 3\n").unwrap();
+			let w = Wordle::from_dictionary(&path).unwrap();
+			let mut words: Vec<_> = w.words_with_frequency().collect();
+			words.sort_unstable();
+			assert_eq!(words, [("cigar", 10), ("rebut", 1), ("sissy", 3)]);
+
+			std::fs::write(&path, "cigar ten\n").unwrap();
+			assert!(matches!(
+				Wordle::from_dictionary(&path),
+				Err(DictionaryError::InvalidFrequency { line: 1, .. })
+			));
+			std::fs::remove_file(&path).unwrap();
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn separate_answers() {
+			let w = Wordle::new();
+			assert_eq!(w.answers().len(), 2309);
+			assert_eq!(w.answers()[0], "cigar");
+			assert!(w.answers().iter().all(|answer| w.contains(answer)));
+
+			let w = Wordle::from_words(["cigar", "rebut", "sissy"]).unwrap().with_answers(["rebut"]).unwrap();
+			assert!(w.contains("cigar"));
+			assert_eq!(w.candidates(&[]).collect::<Vec<_>>(), ["rebut"]);
+			assert!(Wordle::new().with_answers(["bad"]).is_err());
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn word_length_from_dictionary() {
+			assert_eq!(Wordle::new().config(), GameConfig::default());
+			let w = Wordle::from_words(["bead", "read", "road"]).unwrap();
+			assert_eq!(w.config(), GameConfig { word_len: 4, max_guesses: 6 });
+			assert!(w.clone().with_answers(["roads"]).is_err());
+			assert!(matches!(
+				Wordle::from_words(["bead", "beads"]),
+				Err(DictionaryError::InvalidWord { line: 2, .. })
+			));
+			assert!(Wordle::from_words(["abcdefghijk"]).is_err());
+		}
+
+		#[test]
+		fn non_ascii_words() {
+			let w = Wordle::from_words(["čtyři", "řečtí"]).unwrap();
+			assert_eq!(w.config().word_len, 5);
+			let guesser: fn(&[Guess]) -> String = |history| ["řečtí", "čtyři"][history.len()].to_string();
+			let result = w.play("čtyři", guesser).unwrap();
+			assert_eq!(result.solved_in(), Some(2));
+			assert!(Wordle::from_words(["Čtyři"]).is_err());
+			assert!(Wordle::from_words(["čtyř1"]).is_err());
+		}
+
+		#[test]
+		fn six_letters() {
+			let w = Wordle::from_words(["absent", "geezer", "letter"]).unwrap();
+			let guesser: fn(&[Guess]) -> String = |history| ["geezer", "absent"][history.len()].to_string();
+			let result = w.play("absent", guesser).unwrap();
+			assert_eq!(result.solved_in(), Some(2));
+			assert_eq!(result.history[1].mask, mask!(C C C C C C));
+		}
+
+		#[cfg(all(feature = "answers-dictionary", not(feature = "full-dictionary")))]
+		#[test]
+		fn embedded_answers_only() {
+			let w = Wordle::new();
+			assert_eq!(w.answers().len(), 2309);
+			assert_eq!(w.words(), w.answers());
+			assert!(w.contains("cigar"));
+			assert!(!w.contains("salet"));
+			let guesser: fn(&[Guess]) -> String = |_history| "salet".to_string();
+			assert!(matches!(w.play("cigar", guesser), Err(crate::WordleError::NotInDictionary { .. })));
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn frequencies_sum_to_total() {
+			let w = Wordle::new();
+			assert_eq!(w.words_with_frequency().count(), 12947);
+			assert_eq!(w.words_with_frequency().map(|(_, count)| count).sum::<usize>(), 317597603897);
+		}
+	}
+
+	#[cfg(feature = "full-dictionary")]
+	mod game {
+	    use crate::{Guess, Wordle, WordleError, Guesser};
+		use std::cell::Cell;
+		use std::rc::Rc;
+
+		macro_rules! guesser {
+			(|$history:ident| $impl:block) => {{
+				struct G;
+				impl Guesser for G {
+					fn guess(&mut self, $history: &[Guess]) -> String {
+						$impl
+					}
+				}
+				G
+			}};
+		}
+
+		#[test]
+		fn genius() {
+			let w = Wordle::new();
+			let guesser = guesser!(|_history| { "moved".to_string() });
+			assert_eq!(w.play("moved", guesser).unwrap().solved_in(), Some(1));
+		}
+
+		#[test]
+		fn magnificent() {
+			let w = Wordle::new();
+			let guesser = guesser!(|history| {
+				if history.len() == 1 {
+					"right".to_string()
+				}
+				else {
+					"wrong".to_string()
+				}
+			});
+			assert_eq!(w.play("right", guesser).unwrap().solved_in(), Some(2));
+		}
+
+		#[test]
+		fn impressive() {
+			let w = Wordle::new();
+			let guesser = guesser!(|history| {
+				if history.len() == 2 {
+					"right".to_string()
+				}
+				else {
+					"wrong".to_string()
+				}
+			});
+			assert_eq!(w.play("right", guesser).unwrap().solved_in(), Some(3));
+		}
+
+		#[test]
+		fn splendid() {
+			let w = Wordle::new();
+			let guesser = guesser!(|history| {
+				if history.len() == 3 {
+					"right".to_string()
+				}
+				else {
+					"wrong".to_string()
+				}
+			});
+			assert_eq!(w.play("right", guesser).unwrap().solved_in(), Some(4));
+		}
+
+		#[test]
+		fn great() {
+			let w = Wordle::new();
+			let guesser = guesser!(|history| {
+				if history.len() == 4 {
+					"right".to_string()
+				}
+				else {
+					"wrong".to_string()
+				}
+			});
+			assert_eq!(w.play("right", guesser).unwrap().solved_in(), Some(5));
+		}
+
+		#[test]
+		fn phew() {
+			let w = Wordle::new();
+			let guesser = guesser!(|history| {
+				if history.len() == 5 {
+					"right".to_string()
+				}
+				else {
+					"wrong".to_string()
+				}
+			});
+			assert_eq!(w.play("right", guesser).unwrap().solved_in(), Some(6));
+		}
+
+		#[test]
+		fn winning_guess_skips_dictionary_check() {
+			// the win check must come before the dictionary check, or this would be NotInDictionary
+			let w = Wordle::new();
+			assert!(!w.dictionary.contains_key("zzzzz"));
+			let guesser = guesser!(|_history| { "zzzzz".to_string() });
+			assert_eq!(w.play("zzzzz", guesser).unwrap().solved_in(), Some(1));
+		}
+
+		#[test]
+		fn logged_echoes_answer() {
+			let w = Wordle::new();
+			let guesser = guesser!(|_history| { "moved".to_string() });
+			assert_eq!(w.play_logged("moved", guesser).unwrap(), ("moved", Some(1)));
+		}
+
+		#[test]
+		fn hard_mode_allows_hint_respecting_guess() {
+			let w = Wordle::new().with_hard_mode(true);
+			let guesser = guesser!(|history| {
+				match history.len() {
+					0 => "crane".to_string(),
+					1 => "sharp".to_string(),
+					_ => "right".to_string(),
+				}
+			});
+			assert!(w.is_hard_mode());
+			assert_eq!(w.play("right", guesser).unwrap().solved_in(), Some(3));
+		}
+
+		#[test]
+		fn hard_mode_rejects_dropped_yellow() {
+			// "crane" against "right" reveals a yellow 'r', which "sloth" does not use
+			let w = Wordle::new().with_hard_mode(true);
+			let guesser = guesser!(|history| {
+				if history.is_empty() {
+					"crane".to_string()
+				}
+				else {
+					"sloth".to_string()
+				}
+			});
+			assert!(matches!(w.play("right", guesser), Err(WordleError::IgnoresHints { guess }) if guess == "sloth"));
+		}
+
+		#[test]
+		fn invalid_guesses_are_errors() {
+			let w = Wordle::new();
+			let guesser = guesser!(|_history| { "zzzzz".to_string() });
+			assert!(matches!(w.play("right", guesser), Err(WordleError::NotInDictionary { .. })));
+			let guesser = guesser!(|_history| { "rights".to_string() });
+			let error = w.play("right", guesser).unwrap_err();
+			assert!(matches!(error, WordleError::WrongLength { expected: 5, .. }));
+			assert_eq!(error.to_string(), "guess 'rights' does not have 5 letters");
+		}
+
+		#[test]
+		fn is_allowed_requires_yellows() {
+			let w = Wordle::new().with_hard_mode(true);
+			let history = [Guess { word: "slate".into(), mask: mask!(W W M W W) }];
+			assert!(w.is_allowed("cigar", &history));
+			assert!(!w.is_allowed("moved", &history));
+			assert!(Wordle::new().is_allowed("moved", &history));
+			let history = [Guess { word: "crane".into(), mask: mask!(C W W W W) }];
+			assert!(w.is_allowed("cigar", &history));
+			assert!(!w.is_allowed("sigma", &history));
+		}
+
+		#[test]
+		fn oops() {
+			let w = Wordle::new();
+			let guesser = guesser!(|_history| { "wrong".to_string() });
+			assert_eq!(w.play("right", guesser).unwrap().solved_in(), None);
+		}
+
+		#[test]
+		fn result_records_history() {
+			let w = Wordle::new();
+			let guesser = guesser!(|history| {
+				if history.is_empty() {
+					"crane".to_string()
+				}
+				else {
+					"right".to_string()
+				}
+			});
+			let result = w.play("right", guesser).unwrap();
+			assert_eq!(result.answer, "right");
+			assert!(result.won);
+			assert_eq!(result.turns(), 2);
+			assert_eq!(result.history[0], Guess { word: "crane".into(), mask: mask!(W M W W W) });
+			assert_eq!(result.history[1].mask, mask!(C C C C C));
+		}
+
+		#[test]
+		fn runtime_answer() {
+			let w = Wordle::new();
+			let answer = ["ri", "ght"].concat();
+			let guesser = guesser!(|_history| { "right".to_string() });
+			let result = w.play(&answer, guesser).unwrap();
+			assert_eq!(result.solved_in(), Some(1));
+			assert_eq!(result.answer, answer);
+		}
+
+		#[test]
+		fn lost_result_records_every_guess() {
+			let w = Wordle::new();
+			let guesser = guesser!(|_history| { "wrong".to_string() });
+			let result = w.play("right", guesser).unwrap();
+			assert!(!result.won);
+			assert_eq!(result.turns(), 6);
+		}
+
+		struct Counting {
+			calls: Rc<Cell<usize>>,
+			win_at: Option<usize>,
+		}
+
+		impl Guesser for Counting {
+			fn guess(&mut self, history: &[Guess]) -> String {
+				self.calls.set(self.calls.get() + 1);
+				if Some(history.len() + 1) == self.win_at {
+					"right".to_string()
+				}
+				else {
+					"wrong".to_string()
+				}
+			}
+		}
+
+		fn count_calls(win_at: Option<usize>) -> (Option<usize>, usize) {
+			let w = Wordle::new();
+			let calls = Rc::new(Cell::new(0));
+			let result = w.play("right", Counting { calls: Rc::clone(&calls), win_at }).unwrap().solved_in();
+			(result, calls.get())
+		}
+
+		#[test]
+		fn guess_calls_on_early_win() {
+			assert_eq!(count_calls(Some(3)), (Some(3), 3));
+		}
+
+		#[test]
+		fn guess_calls_on_last_turn_win() {
+			assert_eq!(count_calls(Some(6)), (Some(6), 6));
+		}
+
+		#[test]
+		fn guess_calls_on_loss() {
+			assert_eq!(count_calls(None), (None, 6));
+		}
+
+		#[test]
+		fn share_grid() {
+			let w = Wordle::new();
+			let guesser = guesser!(|history| {
+				if history.is_empty() {
+					"crane".to_string()
+				}
+				else {
+					"right".to_string()
+				}
+			});
+			let result = w.play("right", guesser).unwrap();
+			assert_eq!(result.share_grid(Some(245), 6), "Wordle 245 2/6\n\n⬛🟨⬛⬛⬛\n🟩🟩🟩🟩🟩");
+			let guesser = guesser!(|_history| { "wrong".to_string() });
+			let result = w.with_max_guesses(1).play("right", guesser).unwrap();
+			assert_eq!(result.share_grid(None, 1), "Wordle X/1\n\n⬛🟨⬛⬛🟨");
+		}
+
+		#[test]
+		fn max_guesses() {
+			let w = Wordle::new().with_max_guesses(3);
+			let guesser = guesser!(|_history| { "wrong".to_string() });
+			assert_eq!(w.play("right", guesser).unwrap().turns(), 3);
+			let w = w.with_max_guesses(8);
+			let guesser = guesser!(|history| {
+				if history.len() == 7 {
+					"right".to_string()
+				}
+				else {
+					"wrong".to_string()
+				}
+			});
+			assert_eq!(w.play("right", guesser).unwrap().solved_in(), Some(8));
+		}
+
+		#[test]
+		fn unlimited_guesses() {
+			let w = Wordle::new().with_unlimited_guesses(true);
+			let guesser = guesser!(|history| {
+				if history.len() == 9 {
+					"right".to_string()
+				}
+				else {
+					"wrong".to_string()
+				}
+			});
+			let result = w.play("right", guesser).unwrap();
+			assert_eq!(result.solved_in(), Some(10));
+			assert!(w.verify("right", &result.history).is_ok());
+			assert!(Wordle::new().verify("right", &result.history).is_err());
+			// a guesser that never finds the answer still stops
+			let words = ["cigar", "rebut", "sissy"];
+			let w = Wordle::from_words(words).unwrap().with_max_guesses(2).with_unlimited_guesses(true);
+			let guesser = guesser!(|_history| { "cigar".to_string() });
+			assert_eq!(w.play("rebut", guesser).unwrap().turns(), 3);
+		}
+
+		#[test]
+		fn probe_equal_to_answer_does_not_win() {
+			let w = Wordle::new();
+			let guesser = guesser!(|history| {
+				if let Some(probe) = history.first() {
+					assert_eq!(probe.mask, mask!(C C C C C));
+				}
+				"right".to_string()
+			});
+			assert_eq!(w.play_with_probes("right", guesser, 1).unwrap().solved_in(), Some(2));
+		}
+	}
+	
+	mod policy {
+		use super::Scripted;
+		use crate::{Policy, Wordle, WordleError};
+
+		#[test]
+		fn answers() {
+			let w = Wordle::from_words(["cigar", "rebut", "sissy"]).unwrap().with_answers(["cigar", "rebut"]).unwrap();
+			assert!(w.play("sissy", Scripted(&["sissy"])).unwrap().won);
+			assert!(matches!(
+				w.clone().with_answer_policy(Policy::Error).play("sissy", Scripted(&["sissy"])),
+				Err(WordleError::UnknownAnswer { answer }) if answer == "sissy"
+			));
+			assert!(w.clone().with_answer_policy(Policy::Error).play("cigar", Scripted(&["cigar"])).unwrap().won);
+			assert!(w.with_answer_policy(Policy::Warn).play("sissy", Scripted(&["sissy"])).unwrap().won);
+		}
+
+		#[test]
+		fn guesses() {
+			let w = Wordle::from_words(["cigar", "rebut", "sissy"]).unwrap();
+			assert!(matches!(w.play("cigar", Scripted(&["zzzzz"])), Err(WordleError::NotInDictionary { .. })));
+			for policy in [Policy::Warn, Policy::Allow] {
+				let result = w.clone().with_guess_policy(policy).play("cigar", Scripted(&["zzzzz", "cigar"])).unwrap();
+				assert_eq!(result.solved_in(), Some(2));
+			}
+		}
+	}
+
+	mod word_game {
+		use super::Scripted;
+		use crate::{bench, Correctness, GameConfig, Guess, WordGame, Wordle, WordleError};
+
+		/// Wordle without yellows: a letter is either in place or not.
+		struct NoYellows(Wordle);
+
+		impl WordGame for NoYellows {
+			fn config(&self) -> GameConfig {
+				self.0.config()
+			}
+
+			fn feedback(&self, answer: &str, guess: &str) -> Vec<Correctness> {
+				answer.chars().zip(guess.chars())
+					.map(|(a, g)| if a == g { Correctness::Correct } else { Correctness::Wrong })
+					.collect()
+			}
+
+			fn validate(&self, guess: &str, history: &[Guess]) -> Result<(), WordleError> {
+				self.0.validate(guess, history)
+			}
+		}
+
+		fn play<W: WordGame>(game: &W, answer: &str, guesser: Scripted) -> Result<crate::GameResult, WordleError> {
+			game.play(answer, guesser)
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn wordle_plays_as_before() {
+			let w = Wordle::new();
+			let guesses = &["crane", "sloth", "right"];
+			assert_eq!(play(&w, "right", Scripted(guesses)).unwrap(), w.play("right", Scripted(guesses)).unwrap());
+		}
+
+		#[test]
+		fn custom_rules() {
+			let words = ["cigar", "rebut", "sissy", "humph", "awake"];
+			let game = NoYellows(Wordle::from_words(words).unwrap());
+			let result = play(&game, "cigar", Scripted(&["awake", "cigar"])).unwrap();
+			assert!(result.won);
+			assert_eq!(result.history[0].mask, mask!(W W W W W));
+			assert!(matches!(play(&game, "cigar", Scripted(&["zzzzz"])), Err(WordleError::NotInDictionary { .. })));
+			assert!(matches!(play(&game, "cigar", Scripted(&["zzz"])), Err(WordleError::WrongLength { .. })));
+			let stats = bench::run(&game, words, || Scripted(&["cigar", "rebut", "sissy", "humph", "awake"])).unwrap();
+			assert_eq!(stats.wins, [1, 1, 1, 1, 1, 0]);
+		}
+	}
+
+	#[cfg(feature = "full-dictionary")]
+	mod play_async {
+		use super::Scripted;
+		use crate::{AsyncGuesser, Guess, Wordle, WordleError};
+		use std::future::Future;
+		use std::pin::{pin, Pin};
+		use std::task::{Context, Poll, Waker};
+
+		/// Polls `future` until it is done, as a minimal executor.
+		fn block_on<F: Future>(future: F) -> F::Output {
+			let mut future = pin!(future);
+			let mut cx = Context::from_waker(Waker::noop());
+			loop {
+				if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+					return output;
+				}
+			}
+		}
+
+		/// Is pending once before it is ready, like a reply that takes a while.
+		struct Reply(Option<String>, bool);
+
+		impl Future for Reply {
+			type Output = String;
+
+			fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<String> {
+				if !self.1 {
+					self.1 = true;
+					cx.waker().wake_by_ref();
+					return Poll::Pending;
+				}
+				Poll::Ready(self.0.take().expect("polled after completion"))
+			}
+		}
+
+		impl AsyncGuesser for Scripted {
+			fn guess(&mut self, history: &[Guess]) -> impl Future<Output = String> {
+				Reply(Some(self.0[history.len()].to_string()), false)
+			}
+		}
+
+		#[test]
+		fn plays_like_play() {
+			let w = Wordle::new();
+			let result = block_on(w.play_async("cigar", Scripted(&["slate", "micro", "cigar"]))).unwrap();
+			assert_eq!(result.solved_in(), Some(3));
+			assert_eq!(result.history[1].word, "micro");
+			let result = block_on(w.play_async("cigar", &mut Scripted(&["slate"; 6]))).unwrap();
+			assert!(!result.won);
+			assert!(matches!(
+				block_on(w.play_async("cigar", Scripted(&["zzzzz"]))),
+				Err(WordleError::NotInDictionary { .. })
+			));
+		}
+	}
+
+	mod compute {
+		use crate::{Correctness, DuplicateRule};
+
+		#[test]
+		fn all_green() {
+			assert_eq!(
+				Correctness::compute("abcde", "abcde"),
+				mask!(C C C C C)
+			);
+		}
+
+		#[test]
+		fn all_yellow() {
+			assert_eq!(
+				Correctness::compute("abcde", "ecdba"),
+				mask!(M M M M M)
+			);
+		}
+
+		#[test]
+		fn all_gray() {
+			assert_eq!(
+				Correctness::compute("abcde", "fghij"),
+				mask!(W W W W W)
+			);
+		}
+
+		#[test]
+		fn repeat_green() {
+			assert_eq!(
+				Correctness::compute("aabbb", "aaccc"),
+				mask!(C C W W W)
+			);
+		}
+
+		#[test]
+		fn repeat_yellow() {
+			assert_eq!(
+				Correctness::compute("aabbb", "ccaac"),
+				mask!(W W M M W)
+			);
+		}
+
+		#[test]
+		fn repeat_yellow_rules() {
+			assert_eq!(
+				Correctness::compute_with("abccc", "daaad", DuplicateRule::Strict),
+				mask!(W M W W W)
+			);
+			assert_eq!(
+				Correctness::compute_with("abccc", "daaad", DuplicateRule::Lenient),
+				mask!(W M M M W)
+			);
+		}
+
+		#[test]
+		fn repeat_yellow_lenient() {
+			// the answer has two a's, so both rules agree here
+			assert_eq!(
+				Correctness::compute_with("aabbb", "ccaac", DuplicateRule::Lenient),
+				mask!(W W M M W)
+			);
+		}
+
+		#[test]
+		fn repeat_some_green() {
+			assert_eq!(
+				Correctness::compute("xxyyy", "zxxzz"),
+				mask!(W C M W W)
+			);
+		}
+
+		#[test]
+		fn six_letter_repeats() {
+			// the answer has a single 'e', so only the first of the three is yellow
+			assert_eq!(
+				Correctness::compute("absent", "geezer"),
+				mask!(W M W W W W)
+			);
+			assert_eq!(
+				Correctness::compute("settle", "letter"),
+				mask!(M C C C M W)
+			);
+		}
+
+		#[test]
+		#[should_panic(expected = "differ in length")]
+		fn length_mismatch() {
+			Correctness::compute("crane", "cranes");
+		}
+
+		#[test]
+		fn diacritics() {
+			// "č" and "ř" take two bytes each but count as one letter, and "í" is not "i"
+			assert_eq!(
+				Correctness::compute("čtyři", "řečtí"),
+				mask!(M W M M W)
+			);
+			assert_eq!(
+				Correctness::compute("stůně", "stůně"),
+				mask!(C C C C C)
+			);
+		}
+
+		#[test]
+		fn crane_word() {
+			assert_eq!(
+				Correctness::compute("crane", "break"),
+				mask!(W C M M W)
+			);
+		}
+	}
+
+	mod reference {
+		use std::collections::HashMap;
+		use crate::{Correctness, DuplicateRule, PackedCorrectness, PatternMatrix};
+		use crate::dictionary::MAX_WORD_LEN;
+
+		/// The feedback computed the slow way the rules are usually stated: greens first,
+		/// then each other letter is yellow while the answer has copies of it left over.
+		fn reference(answer: &str, guess: &str, rule: DuplicateRule) -> Vec<Correctness> {
+			let answer: Vec<char> = answer.chars().collect();
+			let guess: Vec<char> = guess.chars().collect();
+			let mut left: HashMap<char, usize> = HashMap::new();
+			for (a, g) in answer.iter().zip(&guess) {
+				if a != g {
+					*left.entry(*a).or_default() += 1;
+				}
+			}
+			answer.iter().zip(&guess).map(|(a, g)| {
+				if a == g {
+					return Correctness::Correct;
+				}
+				if rule == DuplicateRule::Lenient {
+					return if answer.contains(g) { Correctness::Misplaced } else { Correctness::Wrong };
+				}
+				match left.get_mut(g) {
+					Some(n) if *n > 0 => {
+						*n -= 1;
+						Correctness::Misplaced
+					}
+					_ => Correctness::Wrong,
+				}
+			}).collect()
+		}
+
+		/// A xorshift generator, so the cases are the same on every run.
+		struct Rng(u64);
+
+		impl Rng {
+			fn below(&mut self, n: usize) -> usize {
+				self.0 ^= self.0 << 13;
+				self.0 ^= self.0 >> 7;
+				self.0 ^= self.0 << 17;
+				(self.0 % n as u64) as usize
+			}
+
+			/// A word over a few letters, so that most words repeat some of them.
+			fn word(&mut self, len: usize) -> String {
+				let letters = ['a', 'b', 'c', 'ě'];
+				(0..len).map(|_| letters[self.below(letters.len())]).collect()
+			}
+		}
+
+		#[test]
+		fn reference_agrees_with_examples() {
+			assert_eq!(reference("cigar", "crane", DuplicateRule::Strict), mask!(C M M W W));
+			assert_eq!(reference("abcde", "aaaaa", DuplicateRule::Strict), mask!(C W W W W));
+			assert_eq!(reference("baaaa", "aaccc", DuplicateRule::Strict), mask!(M C W W W));
+			assert_eq!(reference("abcde", "eaaaa", DuplicateRule::Lenient), mask!(M M M M M));
+		}
+
+		#[test]
+		fn compute_matches_reference() {
+			let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+			for rule in [DuplicateRule::Strict, DuplicateRule::Lenient] {
+				for len in 1..=MAX_WORD_LEN {
+					let words: Vec<String> = (0..24).map(|_| rng.word(len)).collect();
+					let matrix = PatternMatrix::compute(&words, &words, rule);
+					for answer in &words {
+						for guess in &words {
+							let expected = reference(answer, guess, rule);
+							assert_eq!(Correctness::compute_with(answer, guess, rule), expected, "{} against {} ({:?})", guess, answer, rule);
+							assert_eq!(PackedCorrectness::compute(answer, guess, rule).to_mask(len), expected);
+							assert_eq!(matrix.lookup(guess, answer).map(|p| p.to_mask(len)), Some(expected));
+						}
+					}
+				}
+			}
+		}
+	}
+
+	mod mask {
+		use crate::mask::{self, Mask, Style};
+
+		#[test]
+		fn parse_styles() {
+			assert_eq!(mask::parse("GYBbg"), Some(mask!(C M W W C)));
+			assert_eq!(mask::parse("CMWwc"), Some(mask!(C M W W C)));
+			assert_eq!(mask::parse("🟩🟨⬛⬜🟩"), Some(mask!(C M W W C)));
+			assert_eq!(mask::parse("GYBBX"), None);
+			assert_eq!(mask::parse(""), Some(vec![]));
+		}
+
+		#[test]
+		fn display_styles() {
+			let m = mask!(C M W);
+			assert_eq!(mask::display(&m, Style::Feedback).to_string(), "GYB");
+			assert_eq!(mask::display(&m, Style::Compact).to_string(), "CMW");
+			assert_eq!(mask::display(&m, Style::Emoji).to_string(), "🟩🟨⬛");
+			assert_eq!(mask::display(&m, Style::Ansi).to_string(), "\x1b[42m  \x1b[0m\x1b[43m  \x1b[0m\x1b[100m  \x1b[0m");
+			for style in [Style::Feedback, Style::Compact, Style::Emoji] {
+				assert_eq!(mask::parse(&mask::display(&m, style).to_string()), Some(m.clone()));
+			}
+		}
+
+		#[test]
+		fn mask_value() {
+			assert_eq!("CMw".parse(), Ok(Mask(mask!(C M W))));
+			assert_eq!("🟩🟨⬛".parse::<Mask>().unwrap().to_string(), "GYB");
+			assert!("GYX".parse::<Mask>().is_err());
+		}
+	}
+
+	mod render {
+		use crate::{render, Guess};
+		use std::borrow::Cow;
+
+		#[test]
+		fn plain_and_colored() {
+			let guess = Guess { word: Cow::Borrowed("ab"), mask: mask!(C W) };
+			assert_eq!(render::guess(&guess, false).to_string(), "AB GB");
+			assert_eq!(render::guess(&guess, true).to_string(), "\x1b[30;42m A \x1b[0m\x1b[30;100m B \x1b[0m");
+			assert_eq!(render::history(&[guess.clone(), guess], false).to_string(), "AB GB\nAB GB\n");
+		}
+	}
+
+	mod packed {
+		use crate::{Correctness, DuplicateRule, PackedCorrectness};
+
+		#[test]
+		fn known_values() {
+			assert_eq!(PackedCorrectness::from(mask!(W W W W W)).index(), 0);
+			assert_eq!(PackedCorrectness::from(mask!(M W W W W)).index(), 1);
+			assert_eq!(PackedCorrectness::from(mask!(W C W W W)).index(), 6);
+			assert_eq!(PackedCorrectness::from(mask!(C C C C C)).index(), 242);
+		}
+
+		#[test]
+		fn round_trip() {
+			assert_eq!(PackedCorrectness::count(5), 243);
+			for i in 0..PackedCorrectness::count(5) {
+				let packed = PackedCorrectness::from_index(i, 5).unwrap();
+				assert_eq!(PackedCorrectness::from(packed.to_mask(5)), packed);
+			}
+			assert_eq!(PackedCorrectness::from_index(243, 5), None);
+			assert_eq!(PackedCorrectness::correct(5), PackedCorrectness::from(mask!(C C C C C)));
+		}
+
+		#[test]
+		fn patterns() {
+			let all: Vec<_> = Correctness::patterns(5).collect();
+			assert_eq!(all.len(), 243);
+			assert_eq!(all.iter().collect::<std::collections::HashSet<_>>().len(), 243);
+			assert_eq!(all[0], mask!(W W W W W));
+			assert_eq!(all[242], mask!(C C C C C));
+			for (i, mask) in all.iter().enumerate() {
+				assert_eq!(PackedCorrectness::from(&mask[..]).index(), i);
+			}
+			assert_eq!(Correctness::patterns(2).count(), 9);
+		}
+
+		#[test]
+		fn other_lengths() {
+			assert_eq!(PackedCorrectness::count(4), 81);
+			assert_eq!(PackedCorrectness::from(mask!(C C C C)), PackedCorrectness::correct(4));
+			assert_eq!(PackedCorrectness::correct(10).index(), 59048);
+			assert_eq!(
+				PackedCorrectness::compute("banana", "bandit", DuplicateRule::Strict).to_mask(6),
+				mask!(C C C W W W)
+			);
+		}
+
+		#[test]
+		fn compute() {
+			assert_eq!(
+				PackedCorrectness::compute("crane", "break", DuplicateRule::Strict),
+				PackedCorrectness::from(mask!(W C M M W))
+			);
+		}
+	}
+
+	mod matrix {
+		use crate::{DuplicateRule, PackedCorrectness, PatternMatrix};
+
+		#[test]
+		fn lookup() {
+			let m = PatternMatrix::compute(&["break", "crane"], &["crane", "right"], DuplicateRule::Strict);
+			assert_eq!(m.lookup("break", "crane"), Some(PackedCorrectness::from(mask!(W C M M W))));
+			assert_eq!(m.lookup("crane", "right"), Some(PackedCorrectness::from(mask!(W M W W W))));
+			assert_eq!(m.lookup("right", "crane"), None);
+			assert_eq!(m.get(1, 0), PackedCorrectness::from(mask!(C C C C C)));
+		}
+
+		#[test]
+		fn save_and_load() {
+			let words = ["cigar", "rebut", "sissy", "humph"];
+			let m = PatternMatrix::compute(&words, &words[..2], DuplicateRule::Lenient);
+			let path = std::env::temp_dir().join("wordle_matrix_save_and_load.bin");
+			m.save(&path).unwrap();
+			let loaded = PatternMatrix::load(&path).unwrap();
+			std::fs::remove_file(&path).unwrap();
+			assert_eq!(loaded, m);
+			assert_eq!(loaded.rule(), DuplicateRule::Lenient);
+		}
+
+		#[test]
+		fn pattern_width() {
+			// a byte per pattern of five-letter words, two for longer ones
+			for (words, size) in [(["cigar", "rebut"], 47), (["absent", "geezer"], 55)] {
+				let m = PatternMatrix::compute(&words, &words, DuplicateRule::Strict);
+				let path = std::env::temp_dir().join(format!("wordle_matrix_pattern_width_{}.bin", words[0]));
+				m.save(&path).unwrap();
+				let len = std::fs::metadata(&path).unwrap().len();
+				let loaded = PatternMatrix::load(&path).unwrap();
+				std::fs::remove_file(&path).unwrap();
+				assert_eq!(len, size);
+				assert_eq!(loaded, m);
+				assert_eq!(loaded.lookup(words[0], words[0]), Some(PackedCorrectness::correct(words[0].len())));
+			}
+		}
+
+		#[test]
+		fn load_rejects_garbage() {
+			let path = std::env::temp_dir().join("wordle_matrix_load_rejects_garbage.bin");
+			std::fs::write(&path, b"not a matrix").unwrap();
+			assert!(PatternMatrix::load(&path).is_err());
+			std::fs::remove_file(&path).unwrap();
+		}
+	}
+
+	mod transcript {
+		use crate::{bench, replay, GameTranscript, Guess, Wordle};
+		use crate::algorithms::Entropy;
+		use crate::replay::Recorder;
+		#[cfg(feature = "full-dictionary")]
+		use crate::GameResult;
+		#[cfg(feature = "full-dictionary")]
+		use std::time::Duration;
+
+		#[test]
+		fn compact_guess() {
+			let guess = Guess { word: "crane".into(), mask: mask!(W C M M W) };
+			assert_eq!(guess.to_string(), "crane:WCMMW");
+			assert_eq!("crane:WCMMW".parse::<Guess>(), Ok(guess));
+			assert!("crane".parse::<Guess>().is_err());
+			assert!("crane:WCMM".parse::<Guess>().is_err());
+			assert!("crane:WCMMX".parse::<Guess>().is_err());
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn json_round_trip() {
+			let w = Wordle::new();
+			let guesser: fn(&[Guess]) -> String = |history| {
+				["crane", "sloth", "right"][history.len()].to_string()
+			};
+			let transcript = GameTranscript::from(w.play("right", guesser).unwrap());
+			let json = transcript.to_json();
+			assert_eq!(json, r#"{"answer":"right","guesses":["crane:WMWWW","sloth:WWWMM","right:CCCCC"],"won":true}"#);
+			assert_eq!(GameTranscript::from_json(&json), Ok(transcript.clone()));
+			assert_eq!(GameResult::from(transcript).turns(), 3);
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn verify() {
+			let transcript: GameTranscript = GameTranscript::from_json(
+				r#"{"answer":"right","guesses":["crane:WMWWW","right:CCCCC"],"won":true,"elapsed_ms":1.5}"#
+			).unwrap();
+			assert_eq!(transcript.elapsed, Some(Duration::from_micros(1500)));
+			let w = Wordle::new();
+			assert_eq!(transcript.verify(&w), Ok(()));
+			let mut wrong_mask = transcript.clone();
+			wrong_mask.guesses[0] = "crane:WWWWW".parse().unwrap();
+			assert_eq!(wrong_mask.verify(&w), Err("guess 1 'crane' is marked WWWWW but should be WMWWW".to_string()));
+			let mut not_won = transcript.clone();
+			not_won.won = false;
+			assert!(not_won.verify(&w).is_err());
+			let mut goes_on = transcript;
+			goes_on.guesses.push("crane:WMWWW".parse().unwrap());
+			assert!(goes_on.verify(&w).is_err());
+		}
+
+		#[test]
+		fn recorded_bench_replays() {
+			let words = ["cigar", "rebut", "sissy", "humph", "awake"];
+			let w = Wordle::from_words(words).unwrap();
+			let recorder = Recorder::new(Vec::new());
+			let stats = bench::run_recorded(&w, words, || Entropy::new(&w), &recorder).unwrap();
+			let out = recorder.finish().unwrap();
+			let transcripts = replay::read(&out[..]).unwrap();
+			assert_eq!(transcripts.len(), stats.games);
+			assert!(transcripts.iter().all(|t| t.verify(&w).is_ok() && t.elapsed.is_some()));
+			let mut answers: Vec<_> = transcripts.iter().map(|t| t.answer.as_str()).collect();
+			answers.sort_unstable();
+			assert_eq!(answers, ["awake", "cigar", "humph", "rebut", "sissy"]);
+			assert!(replay::read(&b"{}\n"[..]).is_err());
+		}
+
+		#[test]
+		fn json_errors() {
+			assert!(GameTranscript::from_json("").is_err());
+			assert!(GameTranscript::from_json(r#"{"answer":"right","won":true}"#).is_err());
+			assert!(GameTranscript::from_json(r#"{"answer":"right","guesses":["crane:W"],"won":true}"#).is_err());
+			let spaced = r#" { "answer" : "right", "guesses" : [ ], "won" : false, "extra" : [1, null] } "#;
+			assert_eq!(GameTranscript::from_json(spaced).unwrap().guesses, []);
+		}
+	}
+
+	#[cfg(feature = "full-dictionary")]
+	mod verify {
+		use crate::{Correctness, Guess, VerifyError, Wordle};
+
+		fn history(answer: &str, words: &[&'static str]) -> Vec<Guess<'static>> {
+			words.iter().map(|&word| Guess { word: word.into(), mask: Correctness::compute(answer, word) }).collect()
+		}
+
+		#[test]
+		fn accepts_real_games() {
+			let w = Wordle::new();
+			assert_eq!(w.verify("cigar", &history("cigar", &["slate", "micro", "cigar"])), Ok(()));
+			assert_eq!(w.verify("cigar", &history("cigar", &["slate", "micro"])), Ok(()));
+			// the answer counts even if it is not in the dictionary
+			let w = Wordle::from_words(["cigar", "slate"]).unwrap();
+			assert_eq!(w.verify("humph", &history("humph", &["slate", "humph"])), Ok(()));
+		}
+
+		#[test]
+		fn rejects_doctored_games() {
+			let w = Wordle::new();
+			let mut doctored = history("cigar", &["slate", "cigar"]);
+			doctored[0].mask = mask!(W W C W W);
+			assert!(matches!(w.verify("cigar", &doctored), Err(VerifyError::WrongMask { turn: 1, .. })));
+			assert_eq!(
+				w.verify("cigar", &history("cigar", &["cigar", "slate"])),
+				Err(VerifyError::PlayedAfterWin { turn: 1 })
+			);
+			assert_eq!(
+				w.verify("cigar", &history("cigar", &["xxxxx"])),
+				Err(VerifyError::NotInDictionary { turn: 1, guess: "xxxxx".to_string() })
+			);
+			let long = [Guess { word: "cigars".into(), mask: mask!(C C C C C W) }];
+			assert!(matches!(w.verify("cigar", &long), Err(VerifyError::WrongLength { turn: 1, .. })));
+			let too_many = history("cigar", &["slate"; 7]);
+			assert_eq!(w.verify("cigar", &too_many), Err(VerifyError::TooManyGuesses { guesses: 7, max_guesses: 6 }));
+		}
+
+		#[test]
+		fn hard_mode_only_if_enabled() {
+			// "slate" reveals the yellow a, which "moved" drops
+			let game = history("cigar", &["slate", "moved"]);
+			assert_eq!(Wordle::new().verify("cigar", &game), Ok(()));
+			assert_eq!(
+				Wordle::new().with_hard_mode(true).verify("cigar", &game),
+				Err(VerifyError::IgnoresHints { turn: 2, guess: "moved".to_string() })
+			);
+		}
+	}
+
+	mod matches {
+		use crate::{filter_candidates, Guess};
+
+		#[test]
+		fn matches() {
+			let guess = Guess { word: "crane".into(), mask: mask!(W M W W W) };
+			assert!(guess.matches("right"));
+			assert!(!guess.matches("ready"));
+			assert!(!guess.matches("crane"));
+		}
+
+		#[test]
+		fn filter_against_history() {
+			let history = [
+				Guess { word: "crane".into(), mask: mask!(W M W W W) },
+				Guess { word: "sloth".into(), mask: mask!(W W W M M) },
+			];
+			let remaining: Vec<_> = filter_candidates(["right", "third", "sloth", "frump"], &history).collect();
+			assert_eq!(remaining, ["right", "third"]);
+		}
+	}
+
+	mod letter_states {
+		use crate::{Correctness, Guess, LetterState, LetterStates};
+
+		fn history(answer: &str, guesses: &[&'static str]) -> Vec<Guess<'static>> {
+			guesses.iter().map(|&guess| Guess { word: guess.into(), mask: Correctness::compute(answer, guess) }).collect()
+		}
+
+		#[test]
+		fn keyboard() {
+			let states = LetterStates::from_history(&history("cigar", &["crane", "civic"]));
+			assert_eq!(states.state('c'), LetterState::Correct);
+			assert_eq!(states.state('r'), LetterState::Present);
+			assert_eq!(states.state('a'), LetterState::Present);
+			assert_eq!(states.state('v'), LetterState::Absent);
+			assert_eq!(states.state('z'), LetterState::Unknown);
+			assert_eq!((states.green(0), states.green(1), states.green(2)), (Some('c'), Some('i'), None));
+			assert_eq!((states.min_count('c'), states.min_count('i'), states.min_count('n')), (1, 1, 0));
+		}
+
+		#[test]
+		fn hard_mode() {
+			let mut states = LetterStates::new();
+			assert!(states.uses_hints("zzzzz"));
+			for guess in history("cigar", &["crane", "civic"]) {
+				states.update(&guess);
+			}
+			assert!(states.uses_hints("cigar"));
+			assert!(states.uses_hints("circa"));
+			assert!(!states.uses_hints("crane"));
+			assert!(!states.uses_hints("cimba"));
+		}
+	}
+
+	#[cfg(feature = "full-dictionary")]
+	mod candidate_set {
+		use crate::{CandidateSet, Correctness, Guess, Wordle};
+
+		fn guess(answer: &str, word: &'static str) -> Guess<'static> {
+			Guess { word: word.into(), mask: Correctness::compute(answer, word) }
+		}
+
+		#[test]
+		fn prunes_incrementally() {
+			let w = Wordle::new();
+			let mut set = CandidateSet::new(&w);
+			assert_eq!(set.len(), w.answers().len());
+			let mut history = vec![guess("cigar", "slate")];
+			let after_one = set.update(&history).to_vec();
+			assert_eq!(after_one, { let mut c: Vec<_> = w.candidates(&history).collect(); c.sort(); c });
+			history.push(guess("cigar", "micro"));
+			set.update(&history);
+			assert!(set.len() < after_one.len());
+			assert!(set.contains("cigar"));
+			assert!(set.remaining().iter().all(|word| w.is_consistent(word, &history)));
+		}
+
+		#[test]
+		fn starts_over_on_an_unrelated_history() {
+			let w = Wordle::new();
+			let mut set = CandidateSet::new(&w);
+			set.update(&[guess("cigar", "slate"), guess("cigar", "micro")]);
+			let other = [guess("humph", "slate")];
+			let mut expected: Vec<_> = w.candidates(&other).collect();
+			expected.sort();
+			assert_eq!(set.update(&other), &expected[..]);
+			assert_eq!(set.update(&[]).len(), w.answers().len());
+		}
+
+		#[test]
+		fn falls_back_to_dictionary_words() {
+			let w = Wordle::new();
+			assert!(!w.answers().contains(&"zymes"));
+			let mut set = CandidateSet::new(&w);
+			let history = [guess("zymes", "soare"), guess("zymes", "uneth"), guess("zymes", "pesky")];
+			assert!(w.candidates(&history).next().is_none());
+			let remaining = set.update(&history).to_vec();
+			assert!(remaining.contains(&"zymes"));
+			assert_eq!(remaining, w.words().iter().copied().filter(|word| w.is_consistent(word, &history)).collect::<Vec<_>>());
+			let history = [history[0].clone(), history[1].clone(), history[2].clone(), guess("zymes", "zymes")];
+			assert_eq!(set.update(&history), ["zymes"]);
+		}
+
+		#[test]
+		fn guessers_find_dictionary_only_answers() {
+			use crate::algorithms::{Deep, Entropy, Minimax};
+			let w = super::small().0.with_answers(["cigar", "rebut"]).unwrap();
+			for answer in ["humph", "evade"] {
+				assert!(w.play(answer, Entropy::new(&w)).unwrap().won);
+				assert!(w.play(answer, Minimax::new(&w)).unwrap().won);
+				assert!(w.play(answer, Deep::new(&w)).unwrap().won);
+			}
+		}
+	}
+
+	mod constraints {
+		use crate::Constraints;
+		#[cfg(feature = "full-dictionary")]
+		use crate::Wordle;
+
+		#[test]
+		fn parse() {
+			let c = Constraints::parse("_R.ne", "a", "STD", 5).unwrap();
+			assert_eq!(c.green, [None, Some('r'), None, Some('n'), Some('e')]);
+			assert_eq!(c.yellow, ['a']);
+			assert_eq!(c.gray, ['s', 't', 'd']);
+			assert!(Constraints::parse("", "", "", 5).unwrap().is_empty());
+			assert!(Constraints::parse("_r_n", "", "", 5).is_err());
+			assert!(Constraints::parse("_r_n3", "", "", 5).is_err());
+			assert!(Constraints::parse("", "a!", "", 5).is_err());
+		}
+
+		#[test]
+		fn matches() {
+			let c = Constraints::parse("_r_ne", "a", "stdl", 5).unwrap();
+			assert!(c.matches("crane"));
+			assert!(c.matches("arene"));
+			assert!(!c.matches("brine"), "no yellow a");
+			assert!(!c.matches("trane"), "gray t");
+			assert!(!c.matches("crone"), "no yellow a");
+			// a gray letter may still be green or yellow elsewhere
+			let c = Constraints::parse("e____", "", "e", 5).unwrap();
+			assert!(c.matches("eclat"));
+			assert!(!c.matches("eerie"));
+			let c = Constraints::parse("", "ee", "", 5).unwrap();
+			assert!(c.matches("geese"));
+			assert!(!c.matches("crane"));
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn narrow() {
+			let w = Wordle::new();
+			let c = Constraints::parse("_r_ne", "a", "stdl", 5).unwrap();
+			let narrowed = c.narrow(&w);
+			assert!(narrowed.answers().contains(&"crane"));
+			assert!(narrowed.answers().iter().all(|word| c.matches(word)));
+			assert_eq!(narrowed.words().len(), w.words().len());
+			assert!(narrowed.answers().len() < w.answers().len());
+		}
+	}
+
+	mod position_candidates {
+		use crate::{position_candidates, Guess};
+		use std::collections::HashSet;
+
+		#[test]
+		fn green_fixes_position() {
+			let history = [Guess { word: "crane".into(), mask: mask!(C W W W W) }];
+			let candidates = position_candidates(&history, 5);
+			assert_eq!(candidates.len(), 5);
+			assert_eq!(candidates[0], HashSet::from(['c']));
+		}
+
+		#[test]
+		fn yellow_removes_from_guessed_position() {
+			let history = [Guess { word: "crane".into(), mask: mask!(W W M W W) }];
+			let candidates = position_candidates(&history, 5);
+			assert!(!candidates[2].contains(&'a'));
+			assert!(candidates[0].contains(&'a'));
+			assert!(!candidates[0].contains(&'r'));
+		}
+
+		#[test]
+		fn gray_duplicate_only_removes_from_its_position() {
+			let history = [Guess { word: "speed".into(), mask: mask!(W W C W W) }];
+			let candidates = position_candidates(&history, 5);
+			assert_eq!(candidates[2], HashSet::from(['e']));
+			assert!(!candidates[3].contains(&'e'));
+			assert!(candidates[4].contains(&'e'));
+		}
+	}
+
+	mod registry {
+		use crate::algorithms::{algorithm, ALGORITHMS};
+		use crate::{Guesser, Wordle};
+		#[cfg(feature = "full-dictionary")]
+		use crate::{Correctness, Guess};
+
+		#[test]
+		fn lookup_by_name() {
+			assert!(algorithm("entropy").is_some());
+			assert!(algorithm("telepathy").is_none());
+			let names: std::collections::HashSet<_> = ALGORITHMS.iter().map(|a| a.name).collect();
+			assert_eq!(names.len(), ALGORITHMS.len());
+			assert!(!algorithm("random").unwrap().deterministic);
+		}
+
+		#[test]
+		fn dyn_guesser_plays() {
+			let words = ["cigar", "rebut", "sissy", "humph", "awake"];
+			let w = Wordle::from_words(words).unwrap();
+			for algorithm in ALGORITHMS {
+				let mut guesser = (algorithm.build)(&w);
+				let by_ref: &mut dyn Guesser = &mut *guesser;
+				assert!(w.play("humph", by_ref).unwrap().won, "{} lost", algorithm.name);
+				assert!(w.play("sissy", (algorithm.build)(&w)).unwrap().won, "{} lost", algorithm.name);
+			}
+		}
+
+		#[test]
+		fn solves_the_small_dictionary() {
+			let (w, words) = super::small();
+			for algorithm in ALGORITHMS {
+				for answer in words {
+					let result = w.play(answer, (algorithm.build)(&w)).unwrap();
+					assert!(result.won, "{} failed to solve '{}'", algorithm.name, answer);
+					// only the random baseline needs more than three guesses here
+					assert!(!algorithm.deterministic || result.turns() <= 3, "{} was slow on '{}'", algorithm.name, answer);
+				}
+			}
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn guesses_one_of_two_candidates() {
+			// only "cigar" and "circa" remain, and no guess can split them better than either
+			let w = Wordle::new();
+			let history = [
+				Guess { word: "slate".into(), mask: Correctness::compute("cigar", "slate") },
+				Guess { word: "micro".into(), mask: Correctness::compute("cigar", "micro") },
+			];
+			for algorithm in ALGORITHMS {
+				let guess = (algorithm.build)(&w).guess(&history);
+				assert!(guess == "cigar" || guess == "circa", "{} guessed '{}'", algorithm.name, guess);
+			}
+		}
+	}
+
+	#[cfg(feature = "full-dictionary")]
+	mod hint {
+		use crate::{Correctness, Guess, Wordle};
+
+		#[test]
+		fn two_candidates_left() {
+			// only "cigar" and "circa" remain
+			let w = Wordle::new();
+			let history = [
+				Guess { word: "slate".into(), mask: Correctness::compute("cigar", "slate") },
+				Guess { word: "micro".into(), mask: Correctness::compute("cigar", "micro") },
+			];
+			let hints = w.hint(&history, 3);
+			assert_eq!(hints.len(), 3);
+			assert_eq!(hints[0].word, "cigar");
+			assert!(hints[0].is_candidate);
+			assert!((hints[0].bits - 1.0).abs() < 1e-9);
+			assert!((hints[0].expected_remaining - 0.5).abs() < 1e-9);
+			assert_eq!(hints[0].worst_remaining, 1);
+			assert!(!hints[2].is_candidate);
+			assert_eq!(hints[2].worst_remaining, 1);
+			assert!((hints[2].expected_remaining - 1.0).abs() < 1e-9);
+			assert_eq!(w.score("cigar", &history), hints[0]);
+			let pointless = w.score("fuzzy", &history);
+			assert_eq!(pointless.bits, 0.0);
+			assert_eq!(pointless.worst_remaining, 2);
+		}
+	}
+
+	mod positional {
+		use crate::algorithms::PositionalFrequency;
+		use crate::{Guess, Guesser, Wordle};
+
+		#[test]
+		fn common_letters_first() {
+			let w = Wordle::from_words(["cigar", "cider", "tiger", "timer", "rebut"]).unwrap();
+			assert_eq!(PositionalFrequency::new(&w).guess(&[]), "tiger");
+		}
+
+		#[test]
+		fn repeated_letters_count_once_early() {
+			let w = Wordle::from_words(["sissy", "missy", "mossy", "bossy", "lousy"]).unwrap();
+			let mut guesser = PositionalFrequency::new(&w);
+			// mossy would score highest, but its second s repeats a letter
+			assert_eq!(guesser.guess(&[]), "lousy");
+			let miss = Guess { word: "vexed".into(), mask: mask!(W W W W W) };
+			assert_eq!(guesser.guess(&[miss.clone(), miss]), "mossy");
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn plays_answers() {
+			let w = Wordle::new();
+			for answer in ["cigar", "sissy", "humph"] {
+				assert!(w.play(answer, PositionalFrequency::new(&w)).unwrap().won, "lost {}", answer);
+			}
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn answer_outside_the_answer_list() {
+			let w = Wordle::new().with_unlimited_guesses(true);
+			assert!(!w.answers().contains(&"aahed"));
+			assert!(w.play("aahed", PositionalFrequency::new(&w)).unwrap().won);
+			// no word fits both of these
+			let history = ["crane:CCCCC".parse().unwrap(), "crane:WWWWW".parse().unwrap()];
+			assert!(w.contains(&PositionalFrequency::new(&w).guess(&history)));
+		}
+	}
+
+	mod random {
+		use crate::algorithms::Random;
+		use crate::Guesser;
+		#[cfg(feature = "full-dictionary")]
+		use crate::Wordle;
+
+		#[test]
+		fn seeded_and_reproducible() {
+			let w = super::small().0;
+			let first = |seed| Random::new(&w, seed).guess(&[]);
+			assert_eq!(first(7), first(7));
+			let picks: std::collections::HashSet<_> = (0..50).map(first).collect();
+			assert!(picks.len() > 4, "{:?}", picks);
+			for seed in 0..10 {
+				let a = w.play("focal", Random::new(&w, seed)).unwrap();
+				let b = w.play("focal", Random::new(&w, seed)).unwrap();
+				assert!(a.won);
+				assert_eq!(a, b);
+			}
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn answer_outside_the_answer_list() {
+			let w = Wordle::new().with_unlimited_guesses(true);
+			assert!(!w.answers().contains(&"aahed"));
+			assert!(w.play("aahed", Random::new(&w, 1)).unwrap().won);
+			let history = ["crane:CCCCC".parse().unwrap(), "crane:WWWWW".parse().unwrap()];
+			assert!(w.contains(&Random::new(&w, 1).guess(&history)));
+		}
+	}
+
+	#[cfg(feature = "full-dictionary")]
+	mod sanitized {
+		use crate::{Guess, Guesser, Wordle};
+		use crate::algorithms::Sanitized;
+
+		struct Garbage;
 
-pub struct Wordle {
-	dictionary: HashSet<&'static str>,
-}
+		impl Guesser for Garbage {
+			fn guess(&mut self, _history: &[Guess]) -> String {
+				"zzzzz".to_string()
+			}
+		}
 
-impl Wordle {
-	pub fn new() -> Self {
-		Self {
-			dictionary: HashSet::from_iter(DICTIONARY.lines().map(|line| {
-				line.split_once(' ').expect("every line is word + space + frequency").0
-			})),
+		#[test]
+		fn invalid_guess_is_replaced() {
+			let w = Wordle::new().with_unlimited_guesses(true);
+			// the alphabetically first answer stands in for the opener
+			assert_eq!(Sanitized::new(&w, Garbage).guess(&[]), "aback");
+			let result = w.play("right", Sanitized::new(&w, Garbage)).unwrap();
+			assert_eq!(result.history[0].word, "aback");
+			assert!(result.history.iter().all(|guess| w.contains(&guess.word)));
+			assert!(result.won);
 		}
-	}
-	
-	pub fn play<G: Guesser>(&self, answer: &'static str, mut guesser: G) -> Option<usize> {
-		let mut history = Vec::new();
-		for i in 0..6 { // wordle allows 6 guesses
-			let guess = guesser.guess(&history[..]);
-			if guess == answer {
-				return Some(i + 1);
-			}
-			assert!(self.dictionary.contains(&*guess), "guess '{}' is not in the dictionary", guess);
-			let correctness = Correctness::compute(answer, &guess);
-			history.push(Guess {
-				word: guess,
-				mask: correctness
-			});
+
+		#[test]
+		fn replacement_keeps_to_hard_mode() {
+			// once no answer fits, the replacement is a dictionary word that does
+			let w = Wordle::new().with_hard_mode(true).with_unlimited_guesses(true);
+			assert!(!w.answers().contains(&"zymes"));
+			assert!(w.play("zymes", Sanitized::new(&w, Garbage)).unwrap().won);
+		}
+
+		#[test]
+		fn valid_guess_is_kept() {
+			let w = Wordle::new();
+			let inner: fn(&[Guess]) -> String = |_| "right".to_string();
+			let guesser = Sanitized::new(&w, inner);
+			assert_eq!(w.play("right", guesser).unwrap().solved_in(), Some(1));
 		}
-		None
 	}
-}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Correctness {
-    Correct,
-    Misplaced,
-    Wrong,
-}
+	mod openers {
+		use crate::Wordle;
+		use crate::algorithms::optimal_openers;
 
-impl Correctness {
-	fn compute(answer: &str, guess: &str) -> [Self; 5] {
-		assert_eq!(answer.len(), 5);
-		assert_eq!(guess.len(), 5);
-		let mut c = [Correctness::Wrong; 5];
-		// mark things green
-		for (i, (a, g)) in answer.chars().zip(guess.chars()).enumerate() {
-			if a == g {
-				c[i] = Correctness::Correct;
-			}
+		#[test]
+		fn tied_openers() {
+			// bc and cb tell the three candidates apart; any candidate leaves the other two
+			// together, and dd only picks out da
+			let w = Wordle::from_words(["ba", "ca", "da", "bc", "cb", "dd"]).unwrap();
+			assert_eq!(optimal_openers(&w, &["ba", "ca", "da"], 6), ["bc", "cb"]);
+			assert_eq!(optimal_openers(&w, &["ba", "ca", "da"], 2), ["bc", "cb"]);
+			assert!(optimal_openers(&w, &["ba", "ca", "da"], 1).is_empty());
 		}
-		// mark things as yellow
-		let mut used = [false; 5];
-		for (i, &c) in c.iter().enumerate() {
-			if c == Correctness::Correct {
-				used[i] = true;
-			}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn no_candidates() {
+			assert!(optimal_openers(&Wordle::new(), &[], 6).is_empty());
 		}
-		for (i, g) in guess.chars().enumerate() {
-			if c[i] == Correctness::Correct {
-				// Already marked as green
-				continue;
-			}
-			if answer.chars().enumerate().any(|(i, a)| {
-				if a == g && !used[i] {
-					used[i] = true;
-					return true;
-				}
-				false
-			}) {
-				c[i] = Correctness::Misplaced;
-			}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn no_opener_within_one_turn() {
+			let w = Wordle::new();
+			assert!(optimal_openers(&w, &["aback", "abase"], 1).is_empty());
 		}
-		c
 	}
-}
 
-pub struct Guess {
-    pub word: String,
-    pub mask: [Correctness; 5],
-}
+	mod opening_book {
+		use crate::algorithms::{Entropy, OpeningBook};
+		#[cfg(feature = "full-dictionary")]
+		use crate::{Guess, Wordle};
 
-pub trait Guesser {
-    fn guess(&mut self, history: &[Guess]) -> String;
-}
+		#[test]
+		fn plays_the_probes_first() {
+			let (w, words) = super::small();
+			for answer in words {
+				let result = w.play(answer, OpeningBook::new(["humph", "focal"], Entropy::new(&w))).unwrap();
+				let guesses: Vec<_> = result.history.iter().map(|guess| guess.word.to_string()).collect();
+				let probes = if answer == "humph" { &["humph"][..] } else { &["humph", "focal"] };
+				assert_eq!(guesses[..probes.len()], *probes);
+				assert!(result.solved_in().is_some());
+			}
+		}
 
-impl Guesser for fn(history: &[Guess]) -> String {
-	fn guess(&mut self, history: &[Guess]) -> String {
-		(*self)(history)
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn hands_over_after_the_book() {
+			let w = Wordle::new();
+			let guesser: fn(&[Guess]) -> String = |_| "right".to_string();
+			let result = w.play("right", OpeningBook::new(Vec::<String>::new(), guesser)).unwrap();
+			assert_eq!(result.solved_in(), Some(1));
+		}
 	}
-}
 
-#[cfg(test)]
-mod tests {
-	mod game {
-	    use crate::{Guess, Wordle, Guesser};
+	mod entropy {
+		use crate::{Correctness, DuplicateRule, Guess, PatternMatrix, Wordle};
+		use std::sync::Arc;
+		use crate::algorithms::Entropy;
+		#[cfg(feature = "full-dictionary")]
+		use crate::Guesser;
 
-		macro_rules! guesser {
-			(|$history:ident| $impl:block) => {{
-				struct G;
-				impl Guesser for G {
-					fn guess(&mut self, $history: &[Guess]) -> String {
-						$impl
-					}
+		fn history<'a>(answer: &str, guesses: &[&'a str]) -> Vec<Guess<'a>> {
+			guesses.iter().map(|&guess| Guess {
+				word: guess.into(),
+				mask: Correctness::compute(answer, guess),
+			}).collect()
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		fn solve_from(answer: &str, openers: &[&str]) -> usize {
+			let w = Wordle::new();
+			let mut guesser = Entropy::new(&w);
+			let mut history = history(answer, openers);
+			loop {
+				let guess = guesser.guess(&history);
+				assert!(w.dictionary.contains_key(&*guess));
+				if guess == answer {
+					return history.len() + 1;
 				}
-				G
-			}};
+				let mask = Correctness::compute(answer, &guess);
+				history.push(Guess { word: guess.into(), mask });
+				assert!(history.len() < 6, "entropy failed to solve '{}'", answer);
+			}
 		}
 
+		#[cfg(feature = "full-dictionary")]
 		#[test]
-		fn genius() {
+		fn solves_right() {
+			assert!(solve_from("right", &["crane"]) <= 6);
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn solves_cigar() {
+			assert!(solve_from("cigar", &["slate"]) <= 6);
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn single_candidate_is_guessed() {
 			let w = Wordle::new();
-			let guesser = guesser!(|_history| { "moved".to_string() });
-			assert_eq!(w.play("moved", guesser), Some(1));
+			let mut guesser = Entropy::new(&w);
+			let history = history("right", &["crane", "light"]);
+			assert_eq!(guesser.guess(&history), "right");
 		}
 
+		#[cfg(feature = "full-dictionary")]
 		#[test]
-		fn magnificent() {
+		fn prefers_candidate_on_tie() {
+			// only "cigar" and "circa" remain; any guess telling them apart is worth one bit
 			let w = Wordle::new();
-			let guesser = guesser!(|history| {
-				if history.len() == 1 {
-					"right".to_string()
-				}
-				else {
-					"wrong".to_string()
-				}
+			let mut guesser = Entropy::new(&w);
+			let history = history("cigar", &["slate", "micro"]);
+			assert_eq!(guesser.guess(&history), "cigar");
+		}
+
+		#[test]
+		fn custom_dictionary() {
+			let w = Wordle::from_words(["cigar", "rebut", "sissy", "humph", "awake"]).unwrap();
+			let guesser = Entropy::new(&w);
+			let result = w.play("humph", guesser).unwrap();
+			assert!(result.won);
+			assert!(result.history.iter().all(|guess| w.contains(&guess.word)));
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn frequency_prior_prefers_common_answer() {
+			// only the obscure "bumph" and the more common "humph" remain
+			let w = Wordle::new().with_answers(["bumph", "humph"]).unwrap();
+			let history = history("humph", &["crane", "sloth"]);
+			assert_eq!(Entropy::new(&w).guess(&history), "bumph");
+			assert_eq!(Entropy::new(&w).with_frequency_prior().guess(&history), "humph");
+		}
+
+		#[test]
+		fn four_letters() {
+			let words = ["bead", "read", "road", "reed", "lead", "load"];
+			let w = Wordle::from_words(words).unwrap();
+			for answer in words {
+				assert!(w.play(answer, Entropy::new(&w)).unwrap().won);
+			}
+		}
+
+		#[test]
+		fn matrix_gives_same_guesses() {
+			let (w, words) = super::small();
+			let matrix = Arc::new(PatternMatrix::compute(&words, &words, DuplicateRule::Strict));
+			let history = history("focal", &["cigar"]);
+			let plain = Entropy::new(&w).suggest_top(&history, 8);
+			let cached = Entropy::new(&w).with_matrix(matrix).suggest_top(&history, 8);
+			assert_eq!(plain, cached);
+		}
+
+		#[test]
+		fn scorer_replaces_objective() {
+			let w = super::small().0.with_answers(["humph", "blush"]).unwrap();
+			let bits = Entropy::new(&w).with_scorer(|bits, _, _| bits).suggest_top(&[], 8);
+			assert_eq!(bits, Entropy::new(&w).suggest_top(&[], 8));
+			let mut likeliest = Entropy::new(&w).with_scorer(|_, p, remaining| {
+				assert_eq!(remaining, 2);
+				p
 			});
-			assert_eq!(w.play("right", guesser), Some(2));
+			let top = likeliest.suggest_top(&[], 3);
+			assert_eq!(top[0], ("blush".to_string(), 0.5));
+			assert_eq!(top[1], ("humph".to_string(), 0.5));
+			assert_eq!(top[2].1, 0.0);
 		}
 
+		#[cfg(feature = "full-dictionary")]
 		#[test]
-		fn impressive() {
+		fn hard_mode_guess_uses_hints() {
+			let w = Wordle::new().with_hard_mode(true);
+			let mut guesser = Entropy::new(&w);
+			let history = history("right", &["crane", "sloth"]);
+			let guess = guesser.guess(&history);
+			assert!(w.is_allowed(&guess, &history));
+		}
+	}
+
+	#[cfg(feature = "full-dictionary")]
+	mod hosts {
+		use crate::hosts::{Adversarial, Honest};
+		use crate::{Guess, Host, Wordle};
+
+		#[test]
+		fn dodges_largest_bucket() {
+			let w = Wordle::new().with_answers(["right", "light", "might", "sight"]).unwrap();
+			let mut host = Adversarial::new(&w);
+			assert_eq!(host.respond("crane"), mask!(W W W W W));
+			assert_eq!(host.remaining(), ["light", "might", "sight"]);
+		}
+
+		#[test]
+		fn concedes_last_candidate() {
+			let w = Wordle::new().with_answers(["right", "light", "might", "sight"]).unwrap();
+			let guesser: fn(&[Guess]) -> String = |history| {
+				["light", "might", "sight", "right"][history.len()].to_string()
+			};
+			let result = w.play_against(Adversarial::new(&w), guesser).unwrap();
+			// every guess but the last leaves more than one answer, so none of them wins
+			assert_eq!(result.solved_in(), Some(4));
+			assert_eq!(result.answer, "right");
+			assert_eq!(result.history[1].mask, mask!(W C C C C));
+		}
+
+		#[test]
+		fn honest_host_matches_play() {
 			let w = Wordle::new();
-			let guesser = guesser!(|history| {
-				if history.len() == 2 {
-					"right".to_string()
-				}
-				else {
-					"wrong".to_string()
-				}
-			});
-			assert_eq!(w.play("right", guesser), Some(3));
+			let guesser: fn(&[Guess]) -> String = |history| {
+				["crane", "sloth", "right"][history.len()].to_string()
+			};
+			let result = w.play_against(Honest::new(&w, "right"), guesser).unwrap();
+			assert_eq!(result, w.play("right", guesser).unwrap());
+			assert_eq!(result.history[0].mask, mask!(W M W W W));
 		}
+	}
+
+	mod multi {
+		use crate::{is_solved, MultiWordle};
+		use crate::algorithms::Entropy;
+		#[cfg(feature = "full-dictionary")]
+		use crate::{Guess, Guesser, Wordle};
 
 		#[test]
-		fn splendid() {
+		fn solves_every_board() {
+			let w = super::small().0;
+			let game = MultiWordle::new(&w);
+			assert_eq!(game.max_guesses(4), 9);
+			let results = game.play(&["focal", "humph"], Entropy::new(&w)).unwrap();
+			assert_eq!(results.len(), 2);
+			assert!(results.iter().all(|result| result.won));
+			assert_eq!(results[0].answer, "focal");
+			assert!(results.iter().all(|result| is_solved(&result.history)));
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		struct Fixed(&'static [&'static str]);
+
+		#[cfg(feature = "full-dictionary")]
+		impl Guesser for Fixed {
+			fn guess(&mut self, _history: &[Guess]) -> String {
+				unreachable!("guess_multi is overridden")
+			}
+
+			fn guess_multi(&mut self, histories: &[&[Guess]]) -> String {
+				let turn = histories.iter().map(|history| history.len()).max().unwrap_or(0);
+				self.0[turn].to_string()
+			}
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn solved_board_stops_recording() {
 			let w = Wordle::new();
-			let guesser = guesser!(|history| {
-				if history.len() == 3 {
-					"right".to_string()
-				}
-				else {
-					"wrong".to_string()
-				}
-			});
-			assert_eq!(w.play("right", guesser), Some(4));
+			let guesses = &["right", "crane", "sloth", "cigar", "moved", "wrong", "might"];
+			let results = MultiWordle::new(&w).play(&["right", "cigar"], Fixed(guesses)).unwrap();
+			assert_eq!(results[0].solved_in(), Some(1));
+			assert_eq!(results[1].solved_in(), Some(4));
 		}
 
+		#[cfg(feature = "full-dictionary")]
 		#[test]
-		fn great() {
+		fn lost_board() {
 			let w = Wordle::new();
-			let guesser = guesser!(|history| {
-				if history.len() == 4 {
-					"right".to_string()
-				}
-				else {
-					"wrong".to_string()
-				}
-			});
-			assert_eq!(w.play("right", guesser), Some(5));
+			let guesses = &["right", "crane", "sloth", "wrong", "moved", "wrong", "might"];
+			let results = MultiWordle::new(&w).play(&["right", "cigar"], Fixed(guesses)).unwrap();
+			assert!(results[0].won);
+			assert!(!results[1].won);
+			assert_eq!(results[1].turns(), 7);
+		}
+	}
+
+	mod cached {
+		use crate::algorithms::{Cached, Entropy, MoveCache};
+		#[cfg(feature = "full-dictionary")]
+		use crate::{Guess, Guesser, Wordle};
+		#[cfg(feature = "full-dictionary")]
+		use std::cell::Cell;
+		#[cfg(feature = "full-dictionary")]
+		use std::rc::Rc;
+
+		#[cfg(feature = "full-dictionary")]
+		struct Counting(Rc<Cell<usize>>);
+
+		#[cfg(feature = "full-dictionary")]
+		impl Guesser for Counting {
+			fn guess(&mut self, history: &[Guess]) -> String {
+				self.0.set(self.0.get() + 1);
+				["crane", "sloth", "right"][history.len()].to_string()
+			}
 		}
 
+		#[cfg(feature = "full-dictionary")]
 		#[test]
-		fn phew() {
+		fn reuses_early_guesses() {
 			let w = Wordle::new();
-			let guesser = guesser!(|history| {
-				if history.len() == 5 {
-					"right".to_string()
-				}
-				else {
-					"wrong".to_string()
-				}
-			});
-			assert_eq!(w.play("right", guesser), Some(6));
+			let calls = Rc::new(Cell::new(0));
+			let cache = MoveCache::new(2);
+			for _ in 0..3 {
+				let result = w.play("right", Cached::new(Counting(Rc::clone(&calls)), &cache)).unwrap();
+				assert_eq!(result.solved_in(), Some(3));
+			}
+			// two cached turns in the first game, then only the third turn in each game
+			assert_eq!(calls.get(), 5);
+			assert_eq!(cache.len(), 2);
 		}
 
 		#[test]
-		fn oops() {
+		fn same_guesses_as_uncached() {
+			let (w, words) = super::small();
+			let cache = MoveCache::new(2);
+			for answer in words {
+				let plain = w.play(answer, Entropy::new(&w)).unwrap();
+				let cached = w.play(answer, Cached::new(Entropy::new(&w), &cache)).unwrap();
+				assert_eq!(plain, cached);
+			}
+			assert!(!cache.is_empty());
+		}
+	}
+
+	mod budgeted {
+		use crate::algorithms::{Budgeted, Deep, Entropy, Minimax};
+		use crate::{Guess, Guesser};
+		#[cfg(feature = "full-dictionary")]
+		use crate::Wordle;
+		use std::time::{Duration, Instant};
+
+		/// Guesses "cigar", remembering the deadline it was given.
+		struct Recording {
+			deadline: Option<Instant>,
+		}
+
+		impl Guesser for Recording {
+			fn guess(&mut self, _history: &[Guess]) -> String {
+				"cigar".to_string()
+			}
+
+			fn guess_by(&mut self, _history: &[Guess], deadline: Instant) -> String {
+				self.deadline = Some(deadline);
+				"cigar".to_string()
+			}
+		}
+
+		#[test]
+		fn passes_the_budget_on() {
+			let budget = Duration::from_secs(60);
+			let mut inner = Recording { deadline: None };
+			let before = Instant::now();
+			assert_eq!(Budgeted::new(&mut inner, budget).guess(&[]), "cigar");
+			let deadline = inner.deadline.unwrap();
+			assert!(deadline >= before + budget && deadline <= Instant::now() + budget);
+			// an earlier deadline from outside wins
+			Budgeted::new(&mut inner, budget).guess_by(&[], before);
+			assert_eq!(inner.deadline, Some(before));
+		}
+
+		#[test]
+		fn same_guess_in_time() {
+			let w = super::small().0;
+			let budget = Duration::from_secs(60);
+			assert_eq!(Budgeted::new(Entropy::new(&w), budget).guess(&[]), Entropy::new(&w).guess(&[]));
+			assert_eq!(Budgeted::new(Minimax::new(&w), budget).guess(&[]), Minimax::new(&w).guess(&[]));
+			assert_eq!(Budgeted::new(Deep::new(&w), budget).guess(&[]), Deep::new(&w).guess(&[]));
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn best_so_far_without_time() {
+			let w = Wordle::new().with_unlimited_guesses(true);
+			// cut short at once, each guess is the first candidate scored
+			let first = Budgeted::new(Entropy::new(&w), Duration::ZERO).guess(&[]);
+			assert!(w.answers().contains(&first.as_str()));
+			for answer in ["cigar", "humph"] {
+				assert!(w.play(answer, Budgeted::new(Entropy::new(&w), Duration::ZERO)).unwrap().won);
+				assert!(w.play(answer, Budgeted::new(Minimax::new(&w), Duration::ZERO)).unwrap().won);
+			}
+			let w = super::small().0;
+			assert!(w.play("evade", Budgeted::new(Deep::new(&w), Duration::ZERO)).unwrap().won);
+		}
+
+		#[cfg(feature = "full-dictionary")]
+		#[test]
+		fn forwards_the_profile() {
 			let w = Wordle::new();
-			let guesser = guesser!(|_history| { "wrong".to_string() });
-			assert_eq!(w.play("right", guesser), None);
+			let mut guesser = Budgeted::new(Entropy::new(&w), Duration::ZERO);
+			guesser.guess(&[]);
+			assert!(guesser.profile().scoring > Duration::ZERO);
 		}
 	}
-	
-	mod compute {
-		use crate::Correctness;
 
-		macro_rules! mask {
-			(C) => { Correctness::Correct };
-			(M) => { Correctness::Misplaced };
-			(W) => { Correctness::Wrong };
-			($($c:tt)+) => {[
-				$(mask!($c)),+
-			]}
+	mod bench {
+		use std::time::Duration;
+		use crate::bench::{self, Stats, Timing};
+		use crate::{GameResult, Guess, Profile, Wordle};
+		use crate::algorithms::{Entropy, Random};
+
+		fn result(won: bool, turns: usize) -> GameResult {
+			GameResult {
+				answer: "right".to_string(),
+				history: vec![Guess { word: "right".into(), mask: mask!(C C C C C) }; turns],
+				won,
+			}
 		}
 
 		#[test]
-		fn all_green() {
-			assert_eq!(
-				Correctness::compute("abcde", "abcde"),
-				mask!(C C C C C)
-			);
+		fn record() {
+			let mut stats = Stats::new(6);
+			for r in [result(true, 2), result(true, 4), result(true, 4), result(false, 6)] {
+				stats.record(&r);
+			}
+			assert_eq!(stats.games, 4);
+			assert_eq!(stats.wins, [0, 1, 0, 2, 0, 0]);
+			assert_eq!(stats.failures, 1);
+			assert_eq!(stats.worst(), Some(7));
+			assert_eq!(stats.percentile(0.25), Some(2));
+			assert_eq!(stats.percentile(0.5), Some(4));
+			assert_eq!(stats.percentile(0.95), Some(7));
+			assert_eq!(Stats::new(6).percentile(0.5), None);
+			assert!((stats.average() - 10.0 / 3.0).abs() < 1e-9);
+			let mut lost = Stats::new(6);
+			lost.record(&result(false, 6));
+			assert_eq!(lost.average(), 0.0);
+			let mut won = Stats::new(6);
+			assert_eq!(won.worst(), None);
+			won.record(&result(true, 2));
+			won.record(&result(true, 4));
+			assert_eq!(won.worst(), Some(4));
 		}
 
 		#[test]
-		fn all_yellow() {
+		fn export() {
+			let micros = Duration::from_micros;
+			let timing = Timing {
+				elapsed: micros(1500),
+				turns: vec![micros(1000), micros(400)],
+				profile: Profile { filtering: micros(300), scoring: micros(1100) },
+			};
+			let rows = [
+				bench::GameRow::new("entropy", &result(true, 2), &timing),
+				bench::GameRow::new("entropy", &result(false, 1), &Timing { elapsed: micros(20), turns: vec![micros(20)], ..Timing::default() }),
+			];
 			assert_eq!(
-				Correctness::compute("abcde", "ecdba"),
-				mask!(M M M M M)
+				bench::to_csv(&rows),
+				"answer,algorithm,guesses,solved,words,elapsed_us,turn_us,filtering_us,scoring_us\n\
+				right,entropy,2,true,right right,1500,1000 400,300,1100\n\
+				right,entropy,1,false,right,20,20,0,0\n"
+			);
+			assert_eq!(
+				bench::to_json(&rows[1..]),
+				"[\n{\"answer\":\"right\",\"algorithm\":\"entropy\",\"guesses\":1,\"solved\":false,\"words\":[\"right\"],\
+				\"elapsed_us\":20,\"turn_us\":[20],\"filtering_us\":0,\"scoring_us\":0}\n]\n"
 			);
 		}
 
 		#[test]
-		fn all_gray() {
-			assert_eq!(
-				Correctness::compute("abcde", "fghij"),
-				mask!(W W W W W)
-			);
+		fn run() {
+			let words = ["cigar", "rebut", "sissy", "humph", "awake"];
+			let w = Wordle::from_words(words).unwrap();
+			let stats = bench::run(&w, words, || Entropy::new(&w)).unwrap();
+			assert_eq!(stats.games, 5);
+			assert_eq!(stats.failures, 0);
+			assert!(stats.average() >= 1.0);
 		}
 
 		#[test]
-		fn repeat_green() {
-			assert_eq!(
-				Correctness::compute("aabbb", "aaccc"),
-				mask!(C C W W W)
-			);
+		fn parallel_matches_sequential() {
+			let (w, words) = super::small();
+			let mut sequential = bench::run(&w, words, || Entropy::new(&w)).unwrap();
+			let mut parallel = bench::run_parallel(&w, words, || Entropy::new(&w)).unwrap();
+			sequential.elapsed = Default::default();
+			parallel.elapsed = Default::default();
+			assert_eq!(sequential, parallel);
 		}
 
 		#[test]
-		fn repeat_yellow() {
-			assert_eq!(
-				Correctness::compute("aabbb", "ccaac"),
-				mask!(W W M M W)
-			);
+		fn reuses_one_guesser() {
+			let (w, words) = super::small();
+			let mut built = 0;
+			let reused = bench::run(&w, words, || { built += 1; Random::new(&w, 3) }).unwrap();
+			assert_eq!(built, 1);
+			// a reset guesser plays like a new one
+			let mut fresh = Stats::new(6);
+			for answer in words {
+				fresh.record(&w.play(answer, Random::new(&w, 3)).unwrap());
+			}
+			assert_eq!(Stats { elapsed: Default::default(), ..reused }, fresh);
 		}
 
 		#[test]
-		fn repeat_some_green() {
-			assert_eq!(
-				Correctness::compute("xxyyy", "zxxzz"),
-				mask!(W C M W W)
-			);
+		fn evaluate_opener() {
+			let (w, words) = super::small();
+			let report = bench::evaluate_opener(&w, "sissy", words, || Entropy::new(&w)).unwrap();
+			assert_eq!(report.score, w.score("sissy", &[]));
+			assert_eq!(report.stats.games, 8);
+			assert_eq!(report.stats.wins[0], 1);
+			assert!(bench::evaluate_opener(&w, "zzzzz", words, || Entropy::new(&w)).is_err());
 		}
 
 		#[test]
-		fn crane_word() {
-			assert_eq!(
-				Correctness::compute("crane", "break"),
-				mask!(W C M M W)
-			);
+		fn times_turns() {
+			let (w, words) = super::small();
+			let timings = std::sync::Mutex::new(Vec::new());
+			bench::run_parallel_observed(&w, words, || Entropy::new(&w), &|result, timing| {
+				timings.lock().unwrap().push((result.turns(), timing.clone()));
+			}).unwrap();
+			for (turns, timing) in timings.into_inner().unwrap() {
+				assert_eq!(timing.turns.len(), turns);
+				assert!(timing.turns.iter().sum::<Duration>() <= timing.elapsed);
+				assert!(timing.profile.scoring > Duration::ZERO);
+			}
+		}
+
+		#[test]
+		fn wordle_is_send_and_sync() {
+			fn assert_send_sync<T: Send + Sync>() {}
+			assert_send_sync::<Wordle>();
+			assert_send_sync::<Entropy>();
 		}
 	}
-}
\ No newline at end of file
+}
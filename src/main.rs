@@ -1,9 +1,53 @@
-const GAMES: &str = include_str!("../answers.txt");
+mod cli;
+
+use cli::Command;
 
 fn main() {
-    let w = wordle::Wordle::new();
-    for answer in GAMES.split_whitespace() {
-        let guesser = wordle::algorithms::Naive::new();
-        w.play(answer, guesser);
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let color = cli::take_color(&mut args);
+    let w = cli::take_wordle(&mut args);
+    let command = match cli::parse(args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, cli::USAGE);
+            std::process::exit(2);
+        }
+    };
+    let w = match w {
+        Ok(w) => w,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    };
+    let result = match command {
+        Command::Play(options) => cli::play::run(&w, &options, color),
+        Command::Tui => cli::tui::run(&w, color),
+        Command::Stats => cli::stats::run(&w),
+        Command::Solve(options) => cli::assistant::run(&w, &options),
+        Command::Bench(options) => cli::bench::run(&w, &options, color),
+        Command::Compare(options) => cli::compare::run(&w, &options),
+        Command::Evaluate(options) => cli::evaluate::run(&w, &options),
+        Command::Explain { answer, algorithm } => cli::explain::run(&w, &answer, &algorithm, color),
+        Command::Hint { history } => cli::hint::run(&w, &history),
+        Command::Replay { path } => cli::replay::run(&w, &path, color),
+        Command::Serve { addr } => {
+            println!("serving on http://{}", addr);
+            wordle::server::Server::new(&w).serve(&addr).map_err(|e| format!("cannot serve on {}: {}", addr, e))
+        }
+        Command::Algorithms => {
+            for algorithm in wordle::algorithms::ALGORITHMS {
+                println!("{:<10} {}", algorithm.name, algorithm.description);
+            }
+            Ok(())
+        }
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(())
+        }
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        std::process::exit(1);
     }
 }
@@ -0,0 +1,73 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Correctness;
+
+/// A mask as a value that parses with [`parse`] and displays in the [`Style::Feedback`]
+/// style, for where `FromStr` and `Display` are wanted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask(pub Vec<Correctness>);
+
+impl FromStr for Mask {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		parse(s).map(Mask).ok_or_else(|| format!("'{}' is not a mask", s))
+	}
+}
+
+impl fmt::Display for Mask {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		display(&self.0, Style::Feedback).fmt(f)
+	}
+}
+
+/// How [`display`] writes a mask.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Style {
+	/// G for green, Y for yellow and B for gray, as typed into `wordle solve`.
+	#[default]
+	Feedback,
+	/// C for correct, M for misplaced and W for wrong, as in transcripts.
+	Compact,
+	/// The squares of the sharing grid.
+	Emoji,
+	/// Blank squares on ANSI terminal background colors.
+	Ansi,
+}
+
+/// Parses a mask written in any of the letter or emoji styles, e.g. "GYBBG", "CMWWC" or
+/// "🟩🟨⬛⬛🟩". Letters are case-insensitive, and white squares count as gray.
+pub fn parse(s: &str) -> Option<Vec<Correctness>> {
+	s.chars().map(|c| match c.to_ascii_uppercase() {
+		'G' | 'C' | '\u{1F7E9}' => Some(Correctness::Correct),
+		'Y' | 'M' | '\u{1F7E8}' => Some(Correctness::Misplaced),
+		'B' | 'W' | '\u{2B1B}' | '\u{2B1C}' => Some(Correctness::Wrong),
+		_ => None,
+	}).collect()
+}
+
+/// Writes `mask` in the given style.
+pub fn display(mask: &[Correctness], style: Style) -> impl fmt::Display + '_ {
+	Display { mask, style }
+}
+
+struct Display<'a> {
+	mask: &'a [Correctness],
+	style: Style,
+}
+
+impl fmt::Display for Display<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for c in self.mask {
+			// in the order the styles are declared in
+			let symbols = match c {
+				Correctness::Correct => ["G", "C", "\u{1F7E9}", "\x1b[42m  \x1b[0m"],
+				Correctness::Misplaced => ["Y", "M", "\u{1F7E8}", "\x1b[43m  \x1b[0m"],
+				Correctness::Wrong => ["B", "W", "\u{2B1B}", "\x1b[100m  \x1b[0m"],
+			};
+			f.write_str(symbols[self.style as usize])?;
+		}
+		Ok(())
+	}
+}
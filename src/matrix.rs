@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use crate::{DuplicateRule, PackedCorrectness};
+
+const MAGIC: &[u8; 8] = b"WORDLEPM";
+const VERSION: u8 = 3;
+/// The longest words whose patterns fit in a byte: 3^5 = 243.
+const NARROW_WORD_LEN: usize = 5;
+
+/// The precomputed correctness pattern of every guess against every answer.
+///
+/// Building the matrix for the full dictionary takes one `Correctness::compute` per
+/// guess/answer pair and a byte of memory for each (two for words longer than five
+/// letters), so it is mostly worth it when saved to a cache file and loaded again by
+/// later runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternMatrix {
+	rule: DuplicateRule,
+	word_len: usize,
+	guesses: Vec<String>,
+	answers: Vec<String>,
+	guess_index: HashMap<String, usize>,
+	answer_index: HashMap<String, usize>,
+	/// Row-major: the pattern of guess `g` against answer `a` is at `g * answers.len() + a`.
+	patterns: Patterns,
+}
+
+/// The pattern indices of a matrix, in a byte each where they fit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Patterns {
+	Narrow(Vec<u8>),
+	Wide(Vec<u16>),
+}
+
+impl PatternMatrix {
+	/// Computes the patterns of every guess against every answer. All words must have the
+	/// same length.
+	pub fn compute<G, A>(guesses: &[G], answers: &[A], rule: DuplicateRule) -> Self
+	where
+		G: AsRef<str>,
+		A: AsRef<str>,
+	{
+		let word_len = guesses.first().map_or(0, |g| g.as_ref().chars().count());
+		let indices = guesses.iter().flat_map(|guess| {
+			answers.iter().map(move |answer| PackedCorrectness::compute(answer.as_ref(), guess.as_ref(), rule).index())
+		});
+		let patterns = match word_len <= NARROW_WORD_LEN {
+			true => Patterns::Narrow(indices.map(|index| index as u8).collect()),
+			false => Patterns::Wide(indices.map(|index| index as u16).collect()),
+		};
+		Self::from_parts(
+			rule,
+			word_len,
+			guesses.iter().map(|g| g.as_ref().to_string()).collect(),
+			answers.iter().map(|a| a.as_ref().to_string()).collect(),
+			patterns,
+		)
+	}
+
+	fn from_parts(rule: DuplicateRule, word_len: usize, guesses: Vec<String>, answers: Vec<String>, patterns: Patterns) -> Self {
+		let index = |words: &[String]| words.iter().enumerate().map(|(i, w)| (w.clone(), i)).collect();
+		Self {
+			rule,
+			word_len,
+			guess_index: index(&guesses),
+			answer_index: index(&answers),
+			guesses,
+			answers,
+			patterns,
+		}
+	}
+
+	pub fn rule(&self) -> DuplicateRule {
+		self.rule
+	}
+
+	/// The length of the words in the matrix.
+	pub fn word_len(&self) -> usize {
+		self.word_len
+	}
+
+	pub fn guess_index(&self, guess: &str) -> Option<usize> {
+		self.guess_index.get(guess).copied()
+	}
+
+	pub fn answer_index(&self, answer: &str) -> Option<usize> {
+		self.answer_index.get(answer).copied()
+	}
+
+	/// The pattern for the guess and answer at the given indices.
+	pub fn get(&self, guess: usize, answer: usize) -> PackedCorrectness {
+		let i = guess * self.answers.len() + answer;
+		PackedCorrectness(match &self.patterns {
+			Patterns::Narrow(patterns) => patterns[i] as u16,
+			Patterns::Wide(patterns) => patterns[i],
+		})
+	}
+
+	/// The pattern for `guess` against `answer`, if both are in the matrix.
+	pub fn lookup(&self, guess: &str, answer: &str) -> Option<PackedCorrectness> {
+		Some(self.get(self.guess_index(guess)?, self.answer_index(answer)?))
+	}
+
+	pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+		let mut out = BufWriter::new(File::create(path)?);
+		out.write_all(MAGIC)?;
+		out.write_all(&[VERSION, rule_to_byte(self.rule), self.word_len as u8])?;
+		for words in [&self.guesses, &self.answers] {
+			out.write_all(&(words.len() as u32).to_le_bytes())?;
+			for word in words {
+				out.write_all(&[word.len() as u8])?;
+				out.write_all(word.as_bytes())?;
+			}
+		}
+		match &self.patterns {
+			Patterns::Narrow(patterns) => out.write_all(patterns)?,
+			Patterns::Wide(patterns) => out.write_all(&patterns.iter().flat_map(|p| p.to_le_bytes()).collect::<Vec<_>>())?,
+		}
+		out.flush()
+	}
+
+	pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+		let mut input = BufReader::new(File::open(path)?);
+		let mut magic = [0; 8];
+		input.read_exact(&mut magic)?;
+		if &magic != MAGIC {
+			return Err(invalid("not a pattern matrix file"));
+		}
+		let [version] = read_array(&mut input)?;
+		if version != VERSION {
+			return Err(invalid("unsupported pattern matrix version"));
+		}
+		let [rule, word_len] = read_array(&mut input)?;
+		let word_len = word_len as usize;
+		let rule = byte_to_rule(rule).ok_or_else(|| invalid("unknown duplicate rule"))?;
+		let guesses = read_words(&mut input)?;
+		let answers = read_words(&mut input)?;
+		if guesses.iter().chain(&answers).any(|word| word.chars().count() != word_len) {
+			return Err(invalid("word length does not match the matrix"));
+		}
+		let narrow = word_len <= NARROW_WORD_LEN;
+		let mut bytes = vec![0; guesses.len() * answers.len() * if narrow { 1 } else { 2 }];
+		input.read_exact(&mut bytes)?;
+		let patterns = match narrow {
+			true => Patterns::Narrow(bytes),
+			false => Patterns::Wide(bytes.chunks_exact(2).map(|b| u16::from_le_bytes([b[0], b[1]])).collect()),
+		};
+		let in_range = |index: usize| index < PackedCorrectness::count(word_len);
+		let valid = match &patterns {
+			Patterns::Narrow(patterns) => patterns.iter().all(|&p| in_range(p as usize)),
+			Patterns::Wide(patterns) => patterns.iter().all(|&p| in_range(p as usize)),
+		};
+		if !valid {
+			return Err(invalid("pattern out of range"));
+		}
+		Ok(Self::from_parts(rule, word_len, guesses, answers, patterns))
+	}
+}
+
+fn rule_to_byte(rule: DuplicateRule) -> u8 {
+	match rule {
+		DuplicateRule::Strict => 0,
+		DuplicateRule::Lenient => 1,
+	}
+}
+
+fn byte_to_rule(byte: u8) -> Option<DuplicateRule> {
+	match byte {
+		0 => Some(DuplicateRule::Strict),
+		1 => Some(DuplicateRule::Lenient),
+		_ => None,
+	}
+}
+
+fn invalid(message: &str) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn read_array<const N: usize>(input: &mut impl Read) -> io::Result<[u8; N]> {
+	let mut bytes = [0; N];
+	input.read_exact(&mut bytes)?;
+	Ok(bytes)
+}
+
+fn read_words(input: &mut impl Read) -> io::Result<Vec<String>> {
+	let count = u32::from_le_bytes(read_array(input)?) as usize;
+	(0..count).map(|_| {
+		let [len] = read_array(input)?;
+		let mut word = vec![0; len as usize];
+		input.read_exact(&mut word)?;
+		String::from_utf8(word).map_err(|_| invalid("word is not UTF-8"))
+	}).collect()
+}
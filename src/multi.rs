@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+use crate::{Correctness, GameResult, Guess, Guesser, Wordle, WordleError};
+
+/// Plays several boards at once, as in Dordle or Quordle: every guess goes to each board
+/// that is not solved yet, and the game ends when all boards are solved or the guesses
+/// run out.
+///
+/// Guessers are asked through [`Guesser::guess_multi`]. Hard mode is not enforced, since
+/// hints from different boards usually contradict each other.
+pub struct MultiWordle {
+	wordle: Wordle,
+	max_guesses: Option<usize>,
+}
+
+impl MultiWordle {
+	pub fn new(wordle: &Wordle) -> Self {
+		Self { wordle: wordle.clone(), max_guesses: None }
+	}
+
+	/// Sets how many guesses the whole game allows, whatever the number of boards.
+	pub fn with_max_guesses(mut self, max_guesses: usize) -> Self {
+		self.max_guesses = Some(max_guesses);
+		self
+	}
+
+	/// The number of guesses allowed for `boards` boards. Unless set otherwise, that is
+	/// one more than the single-board limit for each extra board, which gives Dordle's 7
+	/// and Quordle's 9.
+	pub fn max_guesses(&self, boards: usize) -> usize {
+		self.max_guesses.unwrap_or(self.wordle.config().max_guesses + boards.saturating_sub(1))
+	}
+
+	/// Plays one board per answer and returns the result of each, in the same order. A
+	/// board's history ends with the guess that solved it. Like [`Wordle::play`], fails
+	/// on a guess that is not allowed.
+	pub fn play<G: Guesser>(&self, answers: &[&str], mut guesser: G) -> Result<Vec<GameResult>, WordleError> {
+		let mut histories: Vec<Vec<Guess<'static>>> = vec![Vec::new(); answers.len()];
+		let mut solved = vec![false; answers.len()];
+		for _ in 0..self.max_guesses(answers.len()) {
+			if solved.iter().all(|&s| s) {
+				break;
+			}
+			let boards: Vec<&[Guess]> = histories.iter().map(Vec::as_slice).collect();
+			let guess = guesser.guess_multi(&boards);
+			let word_len = self.wordle.config().word_len;
+			if guess.chars().count() != word_len {
+				return Err(WordleError::WrongLength { guess, expected: word_len });
+			}
+			let wins = answers.iter().zip(&solved).any(|(&answer, &s)| !s && answer == guess);
+			if !wins && !self.wordle.contains(&guess) {
+				return Err(WordleError::NotInDictionary { guess });
+			}
+			for ((&answer, history), solved) in answers.iter().zip(&mut histories).zip(&mut solved) {
+				if *solved {
+					continue;
+				}
+				let mask = Correctness::compute_with(answer, &guess, self.wordle.duplicate_rule);
+				*solved = mask.iter().all(|&c| c == Correctness::Correct);
+				history.push(Guess { word: Cow::Owned(guess.clone()), mask });
+			}
+		}
+		Ok(answers.iter().zip(histories).zip(solved).map(|((&answer, history), won)| {
+			GameResult { answer: answer.to_string(), history, won }
+		}).collect())
+	}
+}
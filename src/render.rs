@@ -0,0 +1,52 @@
+use std::fmt;
+
+use crate::mask::{self, Style};
+use crate::{Correctness, Guess};
+
+/// Writes `guess` for a terminal. With `color` each letter is shown on a green, yellow or
+/// gray background using ANSI escape codes; without it the word is followed by its
+/// feedback letters, e.g. "CRANE BYBBG".
+pub fn guess<'a>(guess: &'a Guess, color: bool) -> impl fmt::Display + 'a {
+	Render { guess, color }
+}
+
+struct Render<'a> {
+	guess: &'a Guess<'a>,
+	color: bool,
+}
+
+impl fmt::Display for Render<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if !self.color {
+			return write!(f, "{} {}", self.guess.word.to_uppercase(), mask::display(&self.guess.mask, Style::Feedback));
+		}
+		for (letter, c) in self.guess.word.chars().zip(&self.guess.mask) {
+			let background = match c {
+				Correctness::Correct => "42",
+				Correctness::Misplaced => "43",
+				Correctness::Wrong => "100",
+			};
+			write!(f, "\x1b[30;{}m {} \x1b[0m", background, letter.to_uppercase())?;
+		}
+		Ok(())
+	}
+}
+
+/// Writes every guess of `history` on its own line.
+pub fn history<'a>(history: &'a [Guess], color: bool) -> impl fmt::Display + 'a {
+	History { history, color }
+}
+
+struct History<'a> {
+	history: &'a [Guess<'a>],
+	color: bool,
+}
+
+impl fmt::Display for History<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		for g in self.history {
+			writeln!(f, "{}", guess(g, self.color))?;
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,68 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::{GameResult, GameTranscript};
+
+/// Writes a transcript of each game it is given as a line of JSON.
+///
+/// Recording takes `&self`, so one recorder can be shared by the threads of
+/// [`crate::bench::run_recorded`]. Write errors do not interrupt the games; the first one
+/// is kept and returned by [`Recorder::finish`].
+pub struct Recorder<W: Write> {
+	state: Mutex<(W, Option<io::Error>)>,
+}
+
+impl Recorder<BufWriter<File>> {
+	pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+		Ok(Self::new(BufWriter::new(File::create(path)?)))
+	}
+}
+
+impl<W: Write> Recorder<W> {
+	pub fn new(out: W) -> Self {
+		Self { state: Mutex::new((out, None)) }
+	}
+
+	pub fn record(&self, result: &GameResult, elapsed: Duration) {
+		let mut transcript = GameTranscript::from(result.clone());
+		transcript.elapsed = Some(elapsed);
+		let mut state = self.state.lock().expect("recorder lock is not poisoned");
+		let (out, error) = &mut *state;
+		if error.is_none() {
+			if let Err(e) = writeln!(out, "{}", transcript.to_json()) {
+				*error = Some(e);
+			}
+		}
+	}
+
+	/// Flushes the output and returns it, or the first error writing to it.
+	pub fn finish(self) -> io::Result<W> {
+		let (mut out, error) = self.state.into_inner().expect("recorder lock is not poisoned");
+		match error {
+			Some(e) => Err(e),
+			None => out.flush().map(|_| out),
+		}
+	}
+}
+
+/// Reads a file of transcripts written by a [`Recorder`], skipping blank lines.
+pub fn load(path: impl AsRef<Path>) -> io::Result<Vec<GameTranscript>> {
+	read(BufReader::new(File::open(path)?))
+}
+
+pub fn read(input: impl BufRead) -> io::Result<Vec<GameTranscript>> {
+	let mut transcripts = Vec::new();
+	for (i, line) in input.lines().enumerate() {
+		let line = line?;
+		if line.trim().is_empty() {
+			continue;
+		}
+		let transcript = GameTranscript::from_json(&line)
+			.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, format!("line {}: {}", i + 1, e)))?;
+		transcripts.push(transcript);
+	}
+	Ok(transcripts)
+}
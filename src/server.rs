@@ -0,0 +1,293 @@
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::BuildHasher;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+use crate::json::{self, Value};
+use crate::hosts::Honest;
+use crate::{GameState, Wordle};
+
+/// How many hints are returned when the request does not ask for a number.
+const DEFAULT_HINTS: usize = 5;
+
+/// The largest request body read. Every valid body is far smaller, and a larger one is
+/// answered with 413 rather than buffered.
+const MAX_BODY: usize = 4096;
+
+/// How long a connection may take to send its request or receive the response by
+/// default. The server answers one connection at a time, so a client that stalls holds
+/// up every other one until then.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A small JSON-over-HTTP API for playing games, for front-ends that cannot link the
+/// crate:
+///
+/// - `POST /games` starts a game with a random answer and returns it.
+/// - `GET /games/<id>` returns a game: its id, the fields of [`GameState::to_json`]
+///   (without the answer until the game is over), and whether it is won and over.
+/// - `POST /games/<id>/guesses` with a body like `{"guess": "crane"}` plays a guess and
+///   returns the game. Guesses are in the compact style, e.g. "crane:CMWWW".
+/// - `GET /games/<id>/hints?n=5` suggests next guesses.
+/// - `DELETE /games/<id>` forgets a game.
+pub struct Server {
+	wordle: Wordle,
+	games: HashMap<u64, GameState>,
+	next_id: u64,
+	random: RandomState,
+	timeout: Duration,
+}
+
+/// A response: the status code and a JSON body.
+type Response = (u16, String);
+
+impl Server {
+	pub fn new(wordle: &Wordle) -> Self {
+		Self { wordle: wordle.clone(), games: HashMap::new(), next_id: 1, random: RandomState::new(), timeout: DEFAULT_TIMEOUT }
+	}
+
+	/// Sets how long a connection may take to send its request, which is then answered
+	/// with 408, and to receive the response.
+	pub fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = timeout;
+		self
+	}
+
+	/// Accepts connections on `addr` forever, answering one request per connection.
+	pub fn serve<A: ToSocketAddrs>(&mut self, addr: A) -> io::Result<()> {
+		let listener = TcpListener::bind(addr)?;
+		// a connection that fails to be accepted is skipped, and a client that hangs up or
+		// sends garbage should not stop the server either
+		for stream in listener.incoming().flatten() {
+			let _ = self.connection(stream);
+		}
+		Ok(())
+	}
+
+	fn connection(&mut self, mut stream: TcpStream) -> io::Result<()> {
+		stream.set_read_timeout(Some(self.timeout))?;
+		stream.set_write_timeout(Some(self.timeout))?;
+		let (status, body) = match self.respond(&stream) {
+			Ok(response) => response,
+			Err(e) if matches!(e.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut) => {
+				error(408, "timed out waiting for the request")
+			}
+			Err(e) => return Err(e),
+		};
+		write!(
+			stream,
+			"HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+			status, reason(status), body.len(), body
+		)?;
+		stream.flush()
+	}
+
+	/// Reads a request from `stream` and answers it.
+	fn respond(&mut self, stream: &TcpStream) -> io::Result<Response> {
+		let mut reader = BufReader::new(stream);
+		let mut request_line = String::new();
+		reader.read_line(&mut request_line)?;
+		let mut content_length = 0;
+		loop {
+			let mut header = String::new();
+			if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+				break;
+			}
+			if let Some((name, value)) = header.split_once(':') {
+				if name.trim().eq_ignore_ascii_case("content-length") {
+					content_length = value.trim().parse().unwrap_or(0);
+				}
+			}
+		}
+		let mut parts = request_line.split_whitespace();
+		Ok(match (parts.next(), parts.next()) {
+			_ if content_length > MAX_BODY => error(413, "the request body is too large"),
+			(Some(method), Some(target)) => {
+				let mut body = vec![0; content_length];
+				reader.read_exact(&mut body)?;
+				self.handle(method, target, &String::from_utf8_lossy(&body))
+			}
+			_ => error(400, "malformed request line"),
+		})
+	}
+
+	/// Answers one request for `target` (a path with an optional query), returning the
+	/// status code and the JSON body.
+	pub fn handle(&mut self, method: &str, target: &str, body: &str) -> (u16, String) {
+		let (path, query) = target.split_once('?').unwrap_or((target, ""));
+		let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+		let id = match segments.get(1) {
+			Some(id) => match id.parse::<u64>() {
+				Ok(id) if self.games.contains_key(&id) => Some(id),
+				_ => return error(404, "no such game"),
+			},
+			None => None,
+		};
+		match (method, &segments[..], id) {
+			("POST", ["games"], None) => self.create(),
+			("GET", ["games", _], Some(id)) => (200, self.game(id)),
+			("DELETE", ["games", _], Some(id)) => {
+				self.games.remove(&id);
+				(200, "{}".to_string())
+			}
+			("POST", ["games", _, "guesses"], Some(id)) => self.guess(id, body),
+			("GET", ["games", _, "hints"], Some(id)) => self.hints(id, query),
+			_ => error(404, "no such endpoint"),
+		}
+	}
+
+	fn create(&mut self) -> Response {
+		let id = self.next_id;
+		self.next_id += 1;
+		let answers = self.wordle.answers();
+		let answer = answers[self.random.hash_one(id) as usize % answers.len()];
+		self.games.insert(id, GameState::new(&self.wordle, answer, Vec::new()));
+		(201, self.game(id))
+	}
+
+	fn game(&self, id: u64) -> String {
+		let game = &self.games[&id];
+		let Value::Object(state) = game.to_value() else {
+			unreachable!("a game state is an object");
+		};
+		let mut fields = vec![("id".to_string(), Value::Number(id as f64))];
+		fields.extend(state.into_iter().filter(|(key, _)| key != "answer" || game.is_over()));
+		fields.extend([
+			("won".to_string(), Value::Bool(game.is_won())),
+			("over".to_string(), Value::Bool(game.is_over())),
+		]);
+		let mut out = String::new();
+		Value::Object(fields).write(&mut out);
+		out
+	}
+
+	fn guess(&mut self, id: u64, body: &str) -> Response {
+		let guess = match json::parse(body) {
+			Ok(value) => match value.get("guess").and_then(Value::as_str) {
+				Some(guess) => guess.to_lowercase(),
+				None => return error(400, "expected a body like {\"guess\": \"crane\"}"),
+			},
+			Err(e) => return error(400, &e),
+		};
+		let game = self.games.get_mut(&id).expect("handle checked the id");
+		if game.is_over() {
+			return error(409, "the game is over");
+		}
+		let (wordle, answer) = game.resume(&self.wordle).expect("the server only plays valid games");
+		if let Err(e) = wordle.turn(&mut Honest::new(&wordle, &answer), guess, true, &mut game.history) {
+			return error(422, &e.to_string());
+		}
+		(200, self.game(id))
+	}
+
+	fn hints(&self, id: u64, query: &str) -> Response {
+		let n = match query.split('&').find_map(|pair| pair.strip_prefix("n=")) {
+			Some(n) => match n.parse() {
+				Ok(n) => n,
+				Err(_) => return error(400, "invalid number of hints"),
+			},
+			None => DEFAULT_HINTS,
+		};
+		let hints = self.wordle.hint(&self.games[&id].history, n).into_iter().map(|hint| Value::Object(vec![
+			("word".to_string(), Value::String(hint.word)),
+			("bits".to_string(), Value::Number(hint.bits)),
+			("is_candidate".to_string(), Value::Bool(hint.is_candidate)),
+			("expected_remaining".to_string(), Value::Number(hint.expected_remaining)),
+			("worst_remaining".to_string(), Value::Number(hint.worst_remaining as f64)),
+		])).collect();
+		let mut out = String::new();
+		Value::Object(vec![("hints".to_string(), Value::Array(hints))]).write(&mut out);
+		(200, out)
+	}
+}
+
+fn error(status: u16, message: &str) -> Response {
+	let mut out = String::new();
+	Value::Object(vec![("error".to_string(), Value::String(message.to_string()))]).write(&mut out);
+	(status, out)
+}
+
+fn reason(status: u16) -> &'static str {
+	match status {
+		200 => "OK",
+		201 => "Created",
+		400 => "Bad Request",
+		404 => "Not Found",
+		408 => "Request Timeout",
+		409 => "Conflict",
+		413 => "Content Too Large",
+		422 => "Unprocessable Entity",
+		_ => "",
+	}
+}
+
+#[cfg(all(test, feature = "full-dictionary"))]
+mod tests {
+	use std::io::{Read, Write};
+	use std::net::{TcpListener, TcpStream};
+	use std::time::Duration;
+
+	use crate::server::Server;
+	use crate::Wordle;
+
+	#[test]
+	fn plays_a_game() {
+		let w = Wordle::new().with_answers(["cigar"]).unwrap();
+		let mut server = Server::new(&w);
+		let (status, body) = server.handle("POST", "/games", "");
+		assert_eq!(status, 201);
+		assert!(body.starts_with(r#"{"id":1,"#), "{}", body);
+		assert!(!body.contains("cigar"));
+		let (status, body) = server.handle("POST", "/games/1/guesses", r#"{"guess": "Crane"}"#);
+		assert_eq!(status, 200);
+		assert_eq!(
+			body,
+			r#"{"id":1,"guesses":["crane:CMMWW"],"word_len":5,"max_guesses":6,"hard_mode":false,"duplicate_rule":"strict","unlimited":false,"won":false,"over":false}"#
+		);
+		assert_eq!(server.handle("POST", "/games/1/guesses", r#"{"guess": "xxxxx"}"#).0, 422);
+		assert_eq!(server.handle("POST", "/games/1/guesses", r#"{"guess": "toolong"}"#).0, 422);
+		assert_eq!(server.handle("POST", "/games/1/guesses", "crane").0, 400);
+		let (_, body) = server.handle("GET", "/games/1/hints?n=1", "");
+		assert_eq!(body, server.handle("GET", "/games/1/hints?n=1", "").1);
+		assert!(body.contains(r#""word":"cigar""#), "{}", body);
+		let (_, body) = server.handle("POST", "/games/1/guesses", r#"{"guess": "cigar"}"#);
+		assert!(body.starts_with(r#"{"id":1,"answer":"cigar","guesses":["crane:CMMWW","cigar:CCCCC"],"#), "{}", body);
+		assert!(body.ends_with(r#""won":true,"over":true}"#), "{}", body);
+		assert_eq!(server.handle("POST", "/games/1/guesses", r#"{"guess": "cigar"}"#).0, 409);
+		assert_eq!(server.handle("DELETE", "/games/1", "").0, 200);
+		assert_eq!(server.handle("GET", "/games/1", "").0, 404);
+		assert_eq!(server.handle("GET", "/nope", "").0, 404);
+	}
+
+	#[test]
+	fn rejects_huge_bodies() {
+		let mut server = Server::new(&Wordle::new());
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let mut client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+		client.write_all(b"POST /games HTTP/1.1\r\nContent-Length: 99999999999999\r\n\r\n").unwrap();
+		let (stream, _) = listener.accept().unwrap();
+		server.connection(stream).unwrap();
+		let mut response = String::new();
+		client.read_to_string(&mut response).unwrap();
+		assert!(response.starts_with("HTTP/1.1 413 Content Too Large\r\n"), "{}", response);
+		assert!(server.games.is_empty());
+	}
+
+	#[test]
+	fn times_out_stalled_requests() {
+		let mut server = Server::new(&Wordle::new()).with_timeout(Duration::from_millis(50));
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		// one client sends nothing, the other a shorter body than it promised
+		let mut silent = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+		let mut short = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+		short.write_all(b"POST /games/1/guesses HTTP/1.1\r\nContent-Length: 100\r\n\r\n{}").unwrap();
+		for client in [&mut silent, &mut short] {
+			let (stream, _) = listener.accept().unwrap();
+			server.connection(stream).unwrap();
+			let mut response = String::new();
+			client.read_to_string(&mut response).unwrap();
+			assert!(response.starts_with("HTTP/1.1 408 Request Timeout\r\n"), "{}", response);
+		}
+	}
+}
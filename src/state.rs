@@ -0,0 +1,275 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::json::{self, Value};
+use crate::{Correctness, DuplicateRule, GameConfig, Guess, Wordle};
+
+/// A game in progress, saved so that it can be quit and resumed later: the answer, the
+/// guesses so far, and the rules it was played under.
+///
+/// This is the one format in-progress games are stored in, a single line of JSON such as
+/// `{"answer":"right","guesses":["crane:WMWWW"],"word_len":5,"max_guesses":6,
+/// "hard_mode":false,"duplicate_rule":"strict","unlimited":false}`. A saved answer can be
+/// hashed so that it is not spoiled by a glance at the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameState {
+	pub answer: SavedAnswer,
+	pub history: Vec<Guess<'static>>,
+	pub rules: Rules,
+}
+
+/// How the answer of a [`GameState`] is stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SavedAnswer {
+	Plain(String),
+	/// The FNV-1a hash of the answer. It only keeps the answer out of sight: with a word
+	/// list it takes no time to find the word.
+	Hashed(u64),
+}
+
+/// The rules of a [`Wordle`] that change how a game plays out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rules {
+	pub config: GameConfig,
+	pub hard_mode: bool,
+	pub duplicate_rule: DuplicateRule,
+	pub unlimited: bool,
+}
+
+impl Rules {
+	pub fn of(wordle: &Wordle) -> Self {
+		Self {
+			config: wordle.config(),
+			hard_mode: wordle.is_hard_mode(),
+			duplicate_rule: wordle.duplicate_rule(),
+			unlimited: wordle.is_unlimited(),
+		}
+	}
+
+	/// A copy of `wordle` playing by these rules. Fails if the word length differs from
+	/// its dictionary's, which is the one rule a `Wordle` cannot change.
+	pub fn apply(&self, wordle: &Wordle) -> Result<Wordle, String> {
+		if self.config.word_len != wordle.config().word_len {
+			return Err(format!(
+				"the game has {}-letter words but the dictionary has {}-letter words",
+				self.config.word_len, wordle.config().word_len
+			));
+		}
+		Ok(wordle.clone()
+			.with_max_guesses(self.config.max_guesses)
+			.with_hard_mode(self.hard_mode)
+			.with_duplicate_rule(self.duplicate_rule)
+			.with_unlimited_guesses(self.unlimited))
+	}
+}
+
+impl SavedAnswer {
+	pub fn hashed(answer: &str) -> Self {
+		SavedAnswer::Hashed(fnv1a(answer))
+	}
+
+	/// Whether `word` is the saved answer.
+	pub fn is(&self, word: &str) -> bool {
+		match self {
+			SavedAnswer::Plain(answer) => answer == word,
+			SavedAnswer::Hashed(hash) => fnv1a(word) == *hash,
+		}
+	}
+}
+
+impl GameState {
+	/// The state of a game against `answer` under the rules of `wordle`.
+	pub fn new(wordle: &Wordle, answer: &str, history: Vec<Guess<'static>>) -> Self {
+		Self { answer: SavedAnswer::Plain(answer.to_string()), history, rules: Rules::of(wordle) }
+	}
+
+	/// Stores the answer hashed instead of as plain text.
+	pub fn with_hashed_answer(mut self) -> Self {
+		if let SavedAnswer::Plain(answer) = &self.answer {
+			self.answer = SavedAnswer::hashed(answer);
+		}
+		self
+	}
+
+	/// Sets `wordle` up to carry on with the game: returns a copy playing by the saved
+	/// rules, and the answer. A hashed answer is looked up among the answers, then among
+	/// the dictionary words. Fails if the answer cannot be found or the guesses so far
+	/// could not have been played against it.
+	pub fn resume(&self, wordle: &Wordle) -> Result<(Wordle, String), String> {
+		let wordle = self.rules.apply(wordle)?;
+		let answer = match &self.answer {
+			SavedAnswer::Plain(answer) => answer.clone(),
+			SavedAnswer::Hashed(_) => wordle.answers().iter().chain(wordle.words())
+				.find(|word| self.answer.is(word))
+				.ok_or("the hashed answer is not in the word lists")?
+				.to_string(),
+		};
+		wordle.verify(&answer, &self.history).map_err(|e| e.to_string())?;
+		Ok((wordle, answer))
+	}
+
+	/// Whether the last guess was the answer.
+	pub fn is_won(&self) -> bool {
+		self.history.last().is_some_and(|guess| guess.mask.iter().all(|&c| c == Correctness::Correct))
+	}
+
+	/// Whether the game is won or out of guesses.
+	pub fn is_over(&self) -> bool {
+		self.is_won() || (!self.rules.unlimited && self.history.len() >= self.rules.config.max_guesses)
+	}
+
+	/// The state as a single line of JSON.
+	pub fn to_json(&self) -> String {
+		let mut out = String::new();
+		self.to_value().write(&mut out);
+		out
+	}
+
+	/// The fields of [`GameState::to_json`], for formats that embed a game.
+	pub(crate) fn to_value(&self) -> Value {
+		let mut fields = vec![match &self.answer {
+			SavedAnswer::Plain(answer) => ("answer".to_string(), Value::String(answer.clone())),
+			SavedAnswer::Hashed(hash) => ("answer_hash".to_string(), Value::String(format!("{:016x}", hash))),
+		}];
+		fields.extend([
+			("guesses".to_string(), Value::Array(self.history.iter().map(|g| Value::String(g.to_string())).collect())),
+			("word_len".to_string(), Value::Number(self.rules.config.word_len as f64)),
+			("max_guesses".to_string(), Value::Number(self.rules.config.max_guesses as f64)),
+			("hard_mode".to_string(), Value::Bool(self.rules.hard_mode)),
+			("duplicate_rule".to_string(), Value::String(match self.rules.duplicate_rule {
+				DuplicateRule::Strict => "strict",
+				DuplicateRule::Lenient => "lenient",
+			}.to_string())),
+			("unlimited".to_string(), Value::Bool(self.rules.unlimited)),
+		]);
+		Value::Object(fields)
+	}
+
+	/// Reads a state written by [`GameState::to_json`]. Unknown fields are ignored.
+	pub fn from_json(text: &str) -> Result<Self, String> {
+		let value = json::parse(text)?;
+		let field = |key: &str| value.get(key).ok_or_else(|| format!("missing field '{}'", key));
+		let count = |key: &str| field(key)?.as_f64().filter(|n| *n >= 0.0 && n.fract() == 0.0).map(|n| n as usize)
+			.ok_or_else(|| format!("'{}' is not a count", key));
+		let flag = |key: &str| field(key)?.as_bool().ok_or_else(|| format!("'{}' is not a boolean", key));
+		let answer = match (value.get("answer"), value.get("answer_hash")) {
+			(Some(answer), None) => SavedAnswer::Plain(answer.as_str().ok_or("'answer' is not a string")?.to_string()),
+			(None, Some(hash)) => SavedAnswer::Hashed(
+				hash.as_str().and_then(|hash| u64::from_str_radix(hash, 16).ok()).ok_or("'answer_hash' is not a hex hash")?
+			),
+			(Some(_), Some(_)) => return Err("there is both an 'answer' and an 'answer_hash'".to_string()),
+			(None, None) => return Err("missing field 'answer'".to_string()),
+		};
+		let history = field("guesses")?.as_array().ok_or("'guesses' is not an array")?
+			.iter()
+			.map(|g| g.as_str().ok_or_else(|| "guess is not a string".to_string())?.parse())
+			.collect::<Result<_, _>>()?;
+		let duplicate_rule = match field("duplicate_rule")?.as_str() {
+			Some("strict") => DuplicateRule::Strict,
+			Some("lenient") => DuplicateRule::Lenient,
+			_ => return Err("'duplicate_rule' is not \"strict\" or \"lenient\"".to_string()),
+		};
+		let rules = Rules {
+			config: GameConfig { word_len: count("word_len")?, max_guesses: count("max_guesses")? },
+			hard_mode: flag("hard_mode")?,
+			duplicate_rule,
+			unlimited: flag("unlimited")?,
+		};
+		Ok(Self { answer, history, rules })
+	}
+
+	pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+		fs::write(path, self.to_json() + "\n")
+	}
+
+	pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+		Self::from_json(&fs::read_to_string(path)?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+	}
+}
+
+/// The 64-bit FNV-1a hash, which unlike the standard library's hasher is the same in
+/// every build.
+fn fnv1a(word: &str) -> u64 {
+	word.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+#[cfg(all(test, feature = "full-dictionary"))]
+mod tests {
+	use crate::{DuplicateRule, GameState, Guess, SavedAnswer, Wordle};
+
+	fn history(guesses: &[&str]) -> Vec<Guess<'static>> {
+		guesses.iter().map(|g| g.parse().unwrap()).collect()
+	}
+
+	#[test]
+	fn json_round_trip() {
+		let w = Wordle::new().with_hard_mode(true).with_max_guesses(8);
+		let state = GameState::new(&w, "right", history(&["crane:WMWWW"]));
+		let json = state.to_json();
+		assert_eq!(
+			json,
+			r#"{"answer":"right","guesses":["crane:WMWWW"],"word_len":5,"max_guesses":8,"hard_mode":true,"duplicate_rule":"strict","unlimited":false}"#
+		);
+		assert_eq!(GameState::from_json(&json), Ok(state.clone()));
+		let hidden = state.with_hashed_answer();
+		assert!(!hidden.to_json().contains("right"));
+		assert_eq!(GameState::from_json(&hidden.to_json()), Ok(hidden));
+	}
+
+	#[test]
+	fn resumes() {
+		let w = Wordle::new();
+		let state = GameState::new(
+			&w.clone().with_duplicate_rule(DuplicateRule::Lenient).with_unlimited_guesses(true),
+			"right",
+			history(&["crane:WMWWW", "sloth:WWWMM"]),
+		).with_hashed_answer();
+		assert!(matches!(state.answer, SavedAnswer::Hashed(_)));
+		let (resumed, answer) = state.resume(&w).unwrap();
+		assert_eq!(answer, "right");
+		assert_eq!(resumed.duplicate_rule(), DuplicateRule::Lenient);
+		assert!(resumed.is_unlimited());
+		let guesser: fn(&[Guess]) -> String = |history| ["crane", "sloth", "right"][history.len()].to_string();
+		let result = resumed.play(&answer, guesser).unwrap();
+		assert_eq!(result.solved_in(), Some(3));
+		assert_eq!(result.history[..2], state.history);
+	}
+
+	#[test]
+	fn won_and_over() {
+		let w = Wordle::new().with_max_guesses(2);
+		let going = GameState::new(&w, "right", history(&["crane:WMWWW"]));
+		assert!(!going.is_won() && !going.is_over());
+		let lost = GameState::new(&w, "right", history(&["crane:WMWWW", "sloth:WWWMM"]));
+		assert!(!lost.is_won() && lost.is_over());
+		let unlimited = GameState::new(&w.clone().with_unlimited_guesses(true), "right", lost.history.clone());
+		assert!(!unlimited.is_over());
+		let won = GameState::new(&w, "right", history(&["crane:WMWWW", "right:CCCCC"]));
+		assert!(won.is_won() && won.is_over());
+	}
+
+	#[test]
+	fn save_and_load() {
+		let path = std::env::temp_dir().join("wordle_game_state_save_and_load.json");
+		let state = GameState::new(&Wordle::new(), "right", history(&["crane:WMWWW"])).with_hashed_answer();
+		state.save(&path).unwrap();
+		let loaded = GameState::load(&path);
+		std::fs::remove_file(&path).unwrap();
+		assert_eq!(loaded.unwrap(), state);
+	}
+
+	#[test]
+	fn rejects_bad_states() {
+		let w = Wordle::new();
+		let wrong_mask = GameState::new(&w, "right", history(&["crane:WWWWW"]));
+		assert!(wrong_mask.resume(&w).is_err());
+		let unknown = GameState { answer: SavedAnswer::hashed("zzzzz"), ..GameState::new(&w, "right", Vec::new()) };
+		assert!(unknown.resume(&w).is_err());
+		let short = GameState::new(&Wordle::from_words(["abcd"]).unwrap(), "abcd", Vec::new());
+		assert!(short.resume(&w).is_err());
+		assert!(GameState::from_json(r#"{"guesses":[],"word_len":5,"max_guesses":6,"hard_mode":false,"duplicate_rule":"strict","unlimited":false}"#).is_err());
+		assert!(GameState::from_json(r#"{"answer_hash":"xyz","guesses":[],"word_len":5,"max_guesses":6,"hard_mode":false,"duplicate_rule":"strict","unlimited":false}"#).is_err());
+		assert!(GameState::from_json(r#"{"answer":"right","guesses":[],"word_len":5,"max_guesses":6,"hard_mode":false,"duplicate_rule":"loose","unlimited":false}"#).is_err());
+	}
+}
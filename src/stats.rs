@@ -0,0 +1,164 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::json::{self, Value};
+use crate::GameResult;
+
+/// How wide the longest bar of the guess distribution is drawn.
+const BAR_WIDTH: usize = 40;
+
+/// A player's record over many games, like the statistics screen of the official game.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerStats {
+	pub played: usize,
+	pub won: usize,
+	/// Games won in a row, up to the last game.
+	pub current_streak: usize,
+	pub max_streak: usize,
+	/// `distribution[i]` is the number of games won in `i + 1` guesses.
+	pub distribution: Vec<usize>,
+}
+
+impl PlayerStats {
+	pub fn record(&mut self, result: &GameResult) {
+		self.played += 1;
+		match result.solved_in() {
+			Some(n) => {
+				self.won += 1;
+				self.current_streak += 1;
+				self.max_streak = self.max_streak.max(self.current_streak);
+				if self.distribution.len() < n {
+					self.distribution.resize(n, 0);
+				}
+				self.distribution[n - 1] += 1;
+			}
+			None => self.current_streak = 0,
+		}
+	}
+
+	/// The percentage of games won, rounded down.
+	pub fn win_percentage(&self) -> usize {
+		(self.won * 100).checked_div(self.played).unwrap_or(0)
+	}
+
+	pub fn to_json(&self) -> String {
+		let number = |n: usize| Value::Number(n as f64);
+		let mut out = String::new();
+		Value::Object(vec![
+			("played".to_string(), number(self.played)),
+			("won".to_string(), number(self.won)),
+			("current_streak".to_string(), number(self.current_streak)),
+			("max_streak".to_string(), number(self.max_streak)),
+			("distribution".to_string(), Value::Array(self.distribution.iter().map(|&n| number(n)).collect())),
+		]).write(&mut out);
+		out
+	}
+
+	/// Reads stats written by [`PlayerStats::to_json`]. Unknown fields are ignored.
+	pub fn from_json(text: &str) -> Result<Self, String> {
+		let value = json::parse(text)?;
+		let count = |value: &Value| value.as_f64().filter(|n| *n >= 0.0 && n.fract() == 0.0).map(|n| n as usize);
+		let field = |key: &str| {
+			value.get(key).and_then(count).ok_or_else(|| format!("'{}' is missing or not a count", key))
+		};
+		let distribution = value.get("distribution").and_then(Value::as_array)
+			.ok_or("'distribution' is missing or not an array")?
+			.iter()
+			.map(|n| count(n).ok_or_else(|| "'distribution' holds something other than counts".to_string()))
+			.collect::<Result<_, _>>()?;
+		Ok(Self {
+			played: field("played")?,
+			won: field("won")?,
+			current_streak: field("current_streak")?,
+			max_streak: field("max_streak")?,
+			distribution,
+		})
+	}
+}
+
+/// The official statistics screen: the counts, then a bar per number of guesses.
+impl fmt::Display for PlayerStats {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(f, "played:         {}", self.played)?;
+		writeln!(f, "win %:          {}", self.win_percentage())?;
+		writeln!(f, "current streak: {}", self.current_streak)?;
+		writeln!(f, "max streak:     {}", self.max_streak)?;
+		writeln!(f, "\nguess distribution")?;
+		let widest = self.distribution.iter().copied().max().unwrap_or(0).max(1);
+		for (i, &n) in self.distribution.iter().enumerate() {
+			// every row gets a sliver of bar, as in the official game
+			writeln!(f, "{} {} {}", i + 1, "\u{2588}".repeat(1 + n * BAR_WIDTH / widest), n)?;
+		}
+		Ok(())
+	}
+}
+
+/// Where stats are kept by default: `wordle/stats.json` in the user's config directory,
+/// which is `$XDG_CONFIG_HOME` or else `~/.config`. `None` if neither is known.
+pub fn default_path() -> Option<PathBuf> {
+	let config = env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()).map(PathBuf::from)
+		.or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+	Some(config.join("wordle").join("stats.json"))
+}
+
+/// Reads the stats at `path`. A missing file means no games were played yet.
+pub fn load(path: impl AsRef<Path>) -> io::Result<PlayerStats> {
+	match fs::read_to_string(path) {
+		Ok(text) => PlayerStats::from_json(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+		Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(PlayerStats::default()),
+		Err(e) => Err(e),
+	}
+}
+
+/// Writes `stats` to `path`, creating its directory if needed.
+pub fn save(path: impl AsRef<Path>, stats: &PlayerStats) -> io::Result<()> {
+	let path = path.as_ref();
+	if let Some(dir) = path.parent() {
+		fs::create_dir_all(dir)?;
+	}
+	fs::write(path, stats.to_json() + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::stats::{self, PlayerStats};
+	use crate::{Correctness, GameResult, Guess};
+
+	fn result(won: bool, turns: usize) -> GameResult {
+		GameResult {
+			answer: "right".to_string(),
+			history: vec![Guess { word: "right".into(), mask: vec![Correctness::Correct; 5] }; turns],
+			won,
+		}
+	}
+
+	#[test]
+	fn streaks() {
+		let mut player = PlayerStats::default();
+		for r in [result(true, 3), result(true, 4), result(false, 6), result(true, 3)] {
+			player.record(&r);
+		}
+		assert_eq!(player.played, 4);
+		assert_eq!(player.win_percentage(), 75);
+		assert_eq!(player.current_streak, 1);
+		assert_eq!(player.max_streak, 2);
+		assert_eq!(player.distribution, [0, 0, 2, 1]);
+		assert_eq!(PlayerStats::default().win_percentage(), 0);
+	}
+
+	#[test]
+	fn save_and_load() {
+		let mut player = PlayerStats::default();
+		player.record(&result(true, 2));
+		assert_eq!(PlayerStats::from_json(&player.to_json()), Ok(player.clone()));
+		assert!(PlayerStats::from_json(r#"{"played":-1}"#).is_err());
+		let path = std::env::temp_dir().join(format!("wordle-stats-{}", std::process::id())).join("stats.json");
+		assert_eq!(stats::load(&path).unwrap(), PlayerStats::default());
+		stats::save(&path, &player).unwrap();
+		assert_eq!(stats::load(&path).unwrap(), player);
+		std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+	}
+}
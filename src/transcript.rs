@@ -0,0 +1,84 @@
+use std::time::Duration;
+
+use crate::json::{self, Value};
+use crate::{GameResult, Guess, Wordle};
+
+/// A record of one game that can be written to and read back from a line of JSON, for
+/// keeping games around to replay or analyze offline.
+///
+/// Guesses are stored in their compact form, e.g. `{"answer":"right","guesses":
+/// ["crane:WMWWW","right:CCCCC"],"won":true}`. How long the game took is stored as
+/// `"elapsed_ms"` when known.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameTranscript {
+	pub answer: String,
+	pub guesses: Vec<Guess<'static>>,
+	pub won: bool,
+	pub elapsed: Option<Duration>,
+}
+
+impl GameTranscript {
+	/// The transcript as a single line of JSON.
+	pub fn to_json(&self) -> String {
+		let mut fields = vec![
+			("answer".to_string(), Value::String(self.answer.clone())),
+			("guesses".to_string(), Value::Array(self.guesses.iter().map(|g| Value::String(g.to_string())).collect())),
+			("won".to_string(), Value::Bool(self.won)),
+		];
+		if let Some(elapsed) = self.elapsed {
+			fields.push(("elapsed_ms".to_string(), Value::Number(elapsed.as_secs_f64() * 1000.0)));
+		}
+		let mut out = String::new();
+		Value::Object(fields).write(&mut out);
+		out
+	}
+
+	/// Reads a transcript written by [`GameTranscript::to_json`]. Unknown fields are
+	/// ignored.
+	pub fn from_json(text: &str) -> Result<Self, String> {
+		let value = json::parse(text)?;
+		let field = |key: &str| value.get(key).ok_or_else(|| format!("missing field '{}'", key));
+		let answer = field("answer")?.as_str().ok_or("'answer' is not a string")?.to_string();
+		let guesses = field("guesses")?.as_array().ok_or("'guesses' is not an array")?
+			.iter()
+			.map(|g| g.as_str().ok_or_else(|| "guess is not a string".to_string())?.parse())
+			.collect::<Result<_, _>>()?;
+		let won = field("won")?.as_bool().ok_or("'won' is not a boolean")?;
+		let elapsed = match value.get("elapsed_ms") {
+			Some(ms) => {
+				let ms = ms.as_f64().filter(|ms| *ms >= 0.0).ok_or("'elapsed_ms' is not a duration")?;
+				Some(Duration::from_secs_f64(ms / 1000.0))
+			}
+			None => None,
+		};
+		Ok(Self { answer, guesses, won, elapsed })
+	}
+
+	/// Checks that the transcript could have come from a real game of `wordle`, as
+	/// [`Wordle::verify`] does, and that it is marked won exactly when it ends with the
+	/// answer.
+	pub fn verify(&self, wordle: &Wordle) -> Result<(), String> {
+		wordle.verify(&self.answer, &self.guesses).map_err(|e| e.to_string())?;
+		let solved = self.guesses.last().is_some_and(|guess| guess.word == self.answer);
+		if solved != self.won {
+			return Err(format!("game is marked as {} but was {}", won_or_lost(self.won), won_or_lost(solved)));
+		}
+		Ok(())
+	}
+}
+
+fn won_or_lost(won: bool) -> &'static str {
+	if won { "won" } else { "lost" }
+}
+
+impl From<GameResult> for GameTranscript {
+	fn from(result: GameResult) -> Self {
+		Self { answer: result.answer, guesses: result.history, won: result.won, elapsed: None }
+	}
+}
+
+impl From<GameTranscript> for GameResult {
+	fn from(transcript: GameTranscript) -> Self {
+		Self { answer: transcript.answer, history: transcript.guesses, won: transcript.won }
+	}
+}